@@ -29,13 +29,36 @@ pub enum CommandError {
     InvalidInput { field: String, message: String },
     IoError { path: String, message: String },
     DatabaseError { message: String },
+    MigrationFailed { version: u32, message: String, backup_path: Option<String> },
     DeviceNotConnected { device_id: String },
     DeploymentFailed { reason: String },
+    ReadOnlyFilesystem { path: String },
+    DiskFull { path: String, required_bytes: u64, available_bytes: u64 },
+    PermissionDenied { path: String },
+    /// First connection to an SSH host; confirm `fingerprint` via `trust_host_key`.
+    HostKeyUnknown { host: String, fingerprint: String },
+    /// The host presented a different key than the trusted one. Never auto-accepted.
+    HostKeyMismatch { host: String, expected: String, actual: String },
+    AuthenticationFailed { host: String, message: String },
+    /// A required external tool (e.g. `adb`) is not installed or not at the configured path.
+    ToolNotFound { tool: String, searched: Vec<String> },
     Cancelled,
 }
 
 pub type CommandResult<T> = Result<T, CommandError>;
 
+/// One failed item of a batch mutation.
+///
+/// Batch convention: items are applied one by one; successes and failures are both
+/// reported. With `all_or_nothing = true`, any failure rolls back the whole batch and the
+/// result has `rolled_back = true` with no successes applied.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchFailure {
+    pub id: i64,
+    pub error: String,
+}
+
 // --------------------------------- Events ------------------------------------
 
 pub const EVENT_SCAN_PROGRESS: &str = "scan_progress";
@@ -44,14 +67,26 @@ pub const EVENT_DEPLOYMENT_PROGRESS: &str = "deployment_progress";
 pub const EVENT_DEPLOYMENT_COMPLETE: &str = "deployment_complete";
 pub const EVENT_DEVICE_CONNECTED: &str = "device_connected";
 pub const EVENT_DEVICE_DISCONNECTED: &str = "device_disconnected";
+pub const EVENT_HASH_PROGRESS: &str = "hash_progress";
+pub const EVENT_COPY_PROGRESS: &str = "copy_progress";
+pub const EVENT_DEPLOYMENT_QUEUE_DRAINED: &str = "deployment_queue_drained";
+pub const EVENT_LIBRARY_CHANGED: &str = "library_changed";
+/// Payload: the new `DefinitionPackMeta`.
+pub const EVENT_DEFINITIONS_RELOADED: &str = "definitions_reloaded";
+pub const EVENT_DEFINITIONS_RELOAD_FAILED: &str = "definitions_reload_failed";
+pub const EVENT_PACK_DOWNLOAD_PROGRESS: &str = "pack_download_progress";
 
 /// Progress event payload for scans (library scan, BIOS scan, destination scan).
 #[derive(Clone, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ScanProgress {
     pub scan_id: String,
+    /// Files finished so far. Strictly increasing across events even when files are
+    /// processed in parallel.
     pub current: u64,
+    /// Counted by a fast walk before hashing starts; fixed for the scan's lifetime.
     pub total: u64,
+    /// Most recently finished file (workers may complete out of walk order).
     pub current_file: String,
     pub message: Option<String>,
 }
@@ -72,6 +107,8 @@ pub enum ScanKind {
     Library,
     Bios,
     Destination,
+    /// `library::verify_library_integrity`.
+    Integrity,
 }
 
 /// Progress event payload for deployments.
@@ -79,22 +116,159 @@ pub enum ScanKind {
 #[serde(rename_all = "camelCase")]
 pub struct DeploymentProgress {
     pub handle_id: String,
+    /// Items finished so far. Strictly increasing even with parallel copy workers.
     pub current: u64,
     pub total: u64,
+    /// With several workers, the in-flight file with the most bytes left, so a large
+    /// item stays visible while small ones stream past.
     pub current_file: String,
+    /// Bytes already written for `current_file`. Emitted mid-copy at the coalesced event
+    /// rate, so large items advance smoothly.
+    pub current_file_bytes: u64,
+    pub current_file_total: u64,
+    /// Files currently being copied across all workers.
+    pub active_files: u32,
+    /// Cumulative across all finished items plus every partial in-flight file.
     pub bytes_transferred: u64,
+    /// Aggregate across workers.
     pub speed_bps: u64,
+    /// Remaining time from the speed over the last ~10s plus the per-file overhead of the
+    /// files left. `None` for the first few seconds, before there is a sample.
+    pub eta_secs: Option<u64>,
+    pub stage: DeploymentStage,
     pub message: Option<String>,
 }
 
+/// What a deployment is doing with `current_file`.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentStage {
+    Copying,
+    /// CHD conversion into the temp dir; `current_file_bytes` counts input bytes consumed.
+    Compressing,
+    /// Reading the copied file back (`verify_after_copy`); `speed_bps` is read-back speed.
+    Verifying,
+}
+
+/// Progress event payload for a single streamed hash (`fs::calculate_file_hash`).
+///
+/// Only emitted for files of at least 256 MiB, at most every 250ms.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HashProgress {
+    pub operation_id: String,
+    pub path: String,
+    pub bytes: u64,
+    pub total: u64,
+}
+
+/// Progress event payload for `fs::copy_file` calls made with an operation id.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyProgress {
+    pub operation_id: String,
+    pub source: String,
+    pub dest: String,
+    pub bytes_transferred: u64,
+    pub total_bytes: u64,
+    pub speed_bps: u64,
+}
+
 /// Completion event payload for deployments.
 #[derive(Clone, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct DeploymentComplete {
     pub handle_id: String,
     pub success: bool,
+    /// Verification mismatches appear here as `hash mismatch: <dest path>`.
     pub errors: Vec<String>,
     pub warnings: Vec<String>,
+    /// Set when the run used `verify_after_copy`.
+    pub verification: Option<VerificationStats>,
+    pub copy_stats: CopyStats,
+    /// `deploy::pull_saves` only: files changed on both sides, left untouched.
+    pub save_conflicts: Vec<SaveConflict>,
+}
+
+/// A save/state changed both on the device and locally since they were last in sync.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveConflict {
+    pub game_id: i64,
+    pub device_path: String,
+    pub local_path: String,
+    pub device_modified: String,
+    pub local_modified: String,
+}
+
+/// Throughput of a finished deployment, for tuning `copy_concurrency`.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyStats {
+    pub concurrency: u32,
+    /// Total copied bytes over wall-clock copy time.
+    pub aggregate_speed_bps: u64,
+    /// Mean speed of a single file copy. `aggregate / per_file` near 1 means extra workers
+    /// did not help on this medium.
+    pub per_file_speed_bps: u64,
+}
+
+/// Read-back cost of `verify_after_copy`, reported separately from copy throughput.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationStats {
+    pub files_verified: u64,
+    pub mismatches: u64,
+    /// Mismatched files that verified after the automatic re-copy.
+    pub recovered: u64,
+    pub bytes_read: u64,
+    /// Average read-back speed over the run.
+    pub speed_bps: u64,
+}
+
+/// Emitted once the deployment queue has no pending or running entries left. Counts cover
+/// entries finished since the queue was last drained.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueDrained {
+    pub completed: u64,
+    pub failed: u64,
+    pub cancelled: u64,
+}
+
+/// Debounced filesystem changes under the watched library roots.
+///
+/// Paths are absolute and already filtered by `ScanSettings.exclude_globs`. A rename is
+/// reported as a removal plus an addition.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryChanged {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub modified: Vec<String>,
+    /// Roots that disappeared (unmounted/deleted) since the last event. Their games are
+    /// not reported as removed.
+    pub roots_unavailable: Vec<String>,
+    /// Roots that came back; they are rescanned as a whole when auto-rescan is on.
+    pub roots_available: Vec<String>,
+}
+
+/// A changed definition pack could not be loaded; the previous pack stays active.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DefinitionsReloadFailed {
+    pub path: String,
+    pub message: String,
+}
+
+/// Progress event payload for `definitions::download_pack_update`.
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PackDownloadProgress {
+    pub version: String,
+    pub bytes: u64,
+    /// From the manifest's `sizeBytes`.
+    pub total: u64,
 }
 
 /// Device connect/disconnect event payload.
@@ -106,6 +280,23 @@ pub struct DeviceEvent {
 
 // ------------------------------- Core Types ----------------------------------
 
+// ---- Cancellation ----
+
+/// Outcome of `cancel_scan` / `cancel_deployment`.
+///
+/// Cancelling a queued deployment that has not started removes it from the queue and
+/// reports `Cancelled`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum CancelOutcome {
+    /// A running operation was signalled to stop. `id` is its scan id / handle id.
+    Cancelled { id: String },
+    /// No matching operation is active.
+    NothingRunning,
+    /// The operation exists but already completed, failed or was cancelled.
+    AlreadyFinished,
+}
+
 // ---- Library types ----
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -121,6 +312,70 @@ pub struct ScannedGame {
     pub crc32: Option<String>,
     pub sha1: Option<String>,
     pub sha256: Option<String>,
+    /// Tags derived from filename flags (e.g. `(Proto)`, `[b]`) via `ScanSettings.auto_tag_rules`.
+    pub auto_tags: Vec<String>,
+    /// Set when a hack flag (e.g. `[h]`) maps to a rule with `sets_is_hack`.
+    pub is_hack: bool,
+    /// Parsed from filename tags; same rules as `Game.regions`.
+    pub regions: Vec<Region>,
+    pub languages: Vec<String>,
+    /// Same rules as `Game.revision`.
+    pub revision: Option<String>,
+    /// Track files referenced by a `.cue`/`.gdi`/`.ccd` primary entry (absolute paths).
+    /// `file_size` is the combined size of the sheet and all tracks.
+    pub companion_files: Vec<String>,
+    /// Set when `filepath` is a zip/7z holding exactly one recognized ROM: the entry's path
+    /// inside the archive. Hashes and `format` then describe that inner file, while
+    /// `filepath` and `file_size` still describe the archive on disk.
+    pub archive_entry: Option<String>,
+    /// How `platform_id` was decided.
+    pub platform_detection: PlatformDetection,
+    /// Uncompressed size of a CHD's data. `file_size` stays the on-disk size, since that is
+    /// what deployment copies and space checks count.
+    pub logical_size: Option<u64>,
+    /// PS1/PS2 boot serial from `SYSTEM.CNF` (`BOOT`/`BOOT2`), normalized to `SLUS-00594`
+    /// form. `None` when absent or the image is unreadable.
+    pub disc_serial: Option<String>,
+    /// Non-fatal issues for this file, e.g. a multi-file archive hashed as a container.
+    pub warnings: Vec<String>,
+    /// Every disc of a multi-disc set in disc order, including `filepath` (disc 1). Empty for
+    /// single-disc games. `detected_title` has the `(Disc N)` token stripped.
+    pub disc_files: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PlatformSource {
+    /// Matched a `HeaderSignature` in the file's first bytes.
+    Header,
+    /// Extension mapping only (no signature matched).
+    Extension,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlatformDetection {
+    pub source: PlatformSource,
+    pub confidence: Confidence,
+    /// What the extension alone suggested, when it disagrees with the header (e.g. a `.bin`
+    /// that is really a Genesis ROM). The header wins.
+    pub extension_platform_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum TagSource {
+    /// Derived by the scanner; replaced on every rescan.
+    Auto,
+    /// Set by the user; never modified by the scanner.
+    User,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameTag {
+    pub name: String,
+    pub source: TagSource,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -135,6 +390,29 @@ pub struct ScanResult {
     pub updated_games: u64,
     pub errors: Vec<String>,
     pub warnings: Vec<String>,
+    /// Files plus pruned directories skipped by `ScanSettings.exclude_globs`. A pruned
+    /// directory counts once, not per file beneath it.
+    pub excluded_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExcludedPath {
+    pub path: String,
+    /// The first glob that matched.
+    pub pattern: String,
+}
+
+/// Durable progress of an interrupted `rescan_library`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanCheckpoint {
+    pub scan_id: String,
+    pub roots: Vec<String>,
+    pub processed_files: u64,
+    pub total_files: Option<u64>,
+    pub started_at: String, // ISO 8601
+    pub updated_at: String, // ISO 8601
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -152,9 +430,22 @@ pub enum ScanStatus {
 pub struct GameFilter {
     pub platform_ids: Option<Vec<String>>,
     pub collection_id: Option<i64>,
+    /// Full-text match against `display_title` and `raw_title` (FTS5). Terms are ANDed,
+    /// punctuation is ignored and the last term matches as a prefix (`zel` finds Zelda).
+    /// Intersected with every other filter field.
     pub search_query: Option<String>,
     pub has_metadata: Option<bool>,
     pub is_hack: Option<bool>,
+    /// Games carrying all of these tags (auto or user).
+    pub tags: Option<Vec<String>>,
+    /// Games whose `regions` contain any of these.
+    pub regions: Option<Vec<Region>>,
+    /// Games whose `languages` contain any of these (ISO 639-1 codes, e.g. `en`).
+    pub languages: Option<Vec<String>>,
+    /// `true` keeps only games with `disc_files`; `false` excludes them.
+    pub is_multi_disc: Option<bool>,
+    /// Trashed games (`deleted_at` set) are hidden unless this is `true`.
+    pub include_trashed: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -162,8 +453,20 @@ pub struct GameFilter {
 pub struct Pagination {
     pub page: u32,
     pub page_size: u32,
+    /// One of:
+    /// - `title`, `platform`, `fileSize`, `addedAt`
+    /// - `region` / `language`: first region / language
+    /// - `collectionOrder`: stored position; needs a manual `GameFilter.collection_id`
+    /// - `playCount`
+    /// - `lastPlayed`: never-played last
+    /// - `rating`: unrated last
+    /// - `relevance`: FTS rank, best first; needs `search_query`, else falls back to `title`
     pub sort_by: String,
     pub sort_order: SortOrder,
+    /// Opaque keyset cursor from a previous `PaginatedGames.next_cursor`. When set, `page` is
+    /// ignored and the next `page_size` rows after the cursor's `(sort key, id)` are returned.
+    /// Only valid with the same filter and sort it was issued for.
+    pub cursor: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -181,13 +484,33 @@ pub struct PaginatedGames {
     pub page_size: u32,
     pub total_items: u64,
     pub total_pages: u32,
+    /// Cursor for the following page; `None` on the last page.
+    pub next_cursor: Option<String>,
+    /// Only set when `get_games` is called with `include_facets`.
+    pub facets: Option<Facets>,
+}
+
+/// Match counts for sidebar filters. Each map is computed against the active filter
+/// (including `search_query`) minus its own dimension, so selecting a platform does not
+/// collapse the platform list.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Facets {
+    /// Platform id -> matching games, ignoring `GameFilter.platform_ids`.
+    pub per_platform: HashMap<String, u64>,
+    /// Region (serialized `Region` value) -> matching games, ignoring `GameFilter.regions`.
+    /// Multi-region games count once under each of their regions.
+    pub per_region: HashMap<String, u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Game {
     pub id: i64,
-    pub title: String,
+    /// Cleaned title shown in the UI. Editable; never used for matching.
+    pub display_title: String,
+    /// Title derived from the filename at scan time, preserved for DAT/compat matching.
+    pub raw_title: String,
     pub platform_id: String,
     pub filepath: String,
     pub filename: String,
@@ -197,29 +520,195 @@ pub struct Game {
     pub crc32: Option<String>,
     pub sha1: Option<String>,
     pub sha256: Option<String>,
+    /// Track files of a disc sheet; deployed together with `filepath` as one unit.
+    pub companion_files: Vec<String>,
+    /// Inner ROM whose hashes are stored, when `filepath` is a single-ROM archive.
+    pub archive_entry: Option<String>,
+    /// Result of the last `match_against_dat`; `None` if never checked.
+    pub verified: Option<bool>,
+    /// Disc serial read from the image (see `ScannedGame.disc_serial`).
+    pub disc_serial: Option<String>,
+    /// Uncompressed data size for CHD images.
+    pub logical_size: Option<u64>,
+    /// Canonical DAT name from the last successful match.
+    pub dat_name: Option<String>,
+    /// Member discs of a multi-disc set in disc order (first is `filepath`); all are deployed.
+    /// Empty for single-disc games.
+    pub disc_files: Vec<String>,
+    /// Generated `.m3u` listing `disc_files`, if one was written or found next to the discs.
+    pub m3u_path: Option<String>,
     pub has_metadata: bool,
     pub is_hack: bool,
+    pub tags: Vec<GameTag>,
+    /// Regions parsed from the filename, in tag order (`(USA, Europe)` -> `[USA, EUR]`).
+    /// Short aliases map too: `(U)` -> USA, `(E)` -> EUR, `(J)` -> JPN, `(W)` -> World.
+    /// A region-like tag that isn't recognized yields `[Unknown]`; no region tag yields `[]`.
+    pub regions: Vec<Region>,
+    /// ISO 639-1 language codes parsed from the filename (`(En,Fr,De)`).
+    pub languages: Vec<String>,
+    /// Revision tag from the filename, e.g. `(Rev 2)` -> `"2"`, `(Rev A)` -> `"A"`, `(v1.1)` -> `"1.1"`.
+    pub revision: Option<String>,
+    /// Launches recorded via `record_play`.
+    pub play_count: u32,
+    pub last_played: Option<String>, // ISO 8601
+    /// User rating 0-5.
+    pub rating: Option<u8>,
     /// Arbitrary metadata (scraper output, tags, etc).
     pub metadata: Option<JsonValue>,
     pub created_at: String, // ISO 8601
     pub updated_at: String, // ISO 8601
+    /// Set while the game is in the trash (soft-deleted).
+    pub deleted_at: Option<String>, // ISO 8601
+}
+
+/// How `GameUpdate.metadata` combines with a game's stored metadata.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataStrategy {
+    /// Stored metadata becomes exactly the given value.
+    Replace,
+    /// Top-level keys of the given object overwrite stored ones; other keys are kept.
+    MergeShallow,
+    /// Objects merge recursively and arrays are concatenated (stored first). Any other
+    /// value, including `null`, overwrites. So a scraper filling `genre` keeps a manual
+    /// `rating`.
+    MergeDeep,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GameUpdate {
-    pub title: Option<String>,
+    /// Only changes `display_title`; `raw_title` is owned by the scanner.
+    pub display_title: Option<String>,
     pub platform_id: Option<String>,
     pub has_metadata: Option<bool>,
     pub is_hack: Option<bool>,
     pub metadata: Option<JsonValue>,
+    /// How `metadata` is applied; ignored when `metadata` is `None`. Defaults to `Replace`
+    /// for `update_game` and `MergeShallow` for `update_games`.
+    pub metadata_strategy: Option<MetadataStrategy>,
+    /// Replaces the game's user tags. Auto tags are not affected.
+    pub user_tags: Option<Vec<String>>,
+    /// 0-5; anything higher is rejected with `InvalidInput`. `None` leaves it unchanged.
+    pub rating: Option<u8>,
+    /// `Some(true)` removes the rating (the game becomes unrated). Setting it together with
+    /// `rating` is `InvalidInput`.
+    pub clear_rating: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DeleteResult {
     pub deleted_count: u64,
-    pub failed_ids: Vec<i64>,
+    /// Includes games whose files could not be removed; their rows are kept.
+    pub failures: Vec<BatchFailure>,
+    pub rolled_back: bool,
+    /// Games left untouched because a file lies outside every library root.
+    pub skipped_ids: Vec<i64>,
+}
+
+/// Result of `update_games` (see `BatchFailure` for the convention).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBatchResult {
+    /// Rows as stored after the update, in request order; empty when rolled back.
+    pub updated: Vec<Game>,
+    pub failures: Vec<BatchFailure>,
+    pub rolled_back: bool,
+}
+
+/// Category of a library health issue surfaced by `library::get_issues`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum LibraryIssueKind {
+    MissingFile,
+    MissingHashes,
+    UnknownPlatform,
+    NoMetadata,
+    Duplicate,
+    IntegrityFailed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryIssueGroup {
+    pub kind: LibraryIssueKind,
+    pub count: u64,
+    pub game_ids: Vec<i64>,
+    /// True when the category comes from a heavier check (duplicates, integrity) whose
+    /// stored results may be stale; the UI should offer "run scan to refresh".
+    pub needs_refresh: bool,
+    /// When the underlying check last ran (ISO 8601), if known.
+    pub last_checked_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryIssuesReport {
+    pub generated_at: String, // ISO 8601
+    pub total_games: u64,
+    /// Number of distinct games with at least one issue.
+    pub affected_games: u64,
+    pub groups: Vec<LibraryIssueGroup>,
+}
+
+/// A game whose file exists but no longer has the recorded size.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeMismatch {
+    pub game_id: i64,
+    pub expected_bytes: u64,
+    pub actual_bytes: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReport {
+    pub scan_id: String,
+    pub checked: u64,
+    pub ok: Vec<i64>,
+    /// `filepath` (or any companion/disc file) is gone.
+    pub missing: Vec<i64>,
+    /// Only filled when sizes were checked.
+    pub size_changed: Vec<SizeMismatch>,
+    pub started_at: String,  // ISO 8601
+    pub finished_at: String, // ISO 8601
+}
+
+/// Games sharing a content hash, ordered best keeper first.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateGroup {
+    pub hash: String,
+    /// Hash the group was formed on; weaker than requested when a member lacked it.
+    pub algorithm: HashAlgorithm,
+    /// Ordered by: inside a library root, preferred region (`region_priority`), verified
+    /// against a DAT, shortest path.
+    pub games: Vec<Game>,
+    /// Id of `games[0]`.
+    pub suggested_keep: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BackfillResult {
+    pub examined: u64,
+    pub updated: u64,
+    pub errors: Vec<String>,
+}
+
+/// Result of matching one game against a No-Intro/Redump DAT.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DatMatch {
+    /// `<game name>` from the DAT; `None` when nothing matched.
+    pub canonical_name: Option<String>,
+    /// Region of the matched DAT entry, parsed from its name or `<region>`.
+    pub region: Option<Region>,
+    /// True when the ROM hash matched a DAT entry exactly.
+    pub verified: bool,
+    /// Strongest hash that matched (sha1 > md5 > crc32).
+    pub matched_by: Option<HashAlgorithm>,
 }
 
 // ---- Collections ----
@@ -229,7 +718,13 @@ pub struct DeleteResult {
 pub struct Collection {
     pub id: i64,
     pub name: String,
+    /// Manual collections: members in their stored position order. Smart collections: the
+    /// live membership resolved from `filter`.
     pub game_ids: Vec<i64>,
+    /// True for collections defined by a `GameFilter` instead of explicit members.
+    pub is_smart: bool,
+    /// Stored filter of a smart collection; `None` for manual ones.
+    pub filter: Option<GameFilter>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -238,6 +733,39 @@ pub struct Collection {
 #[serde(rename_all = "camelCase")]
 pub struct CollectionUpdate {
     pub name: Option<String>,
+    /// Replaces a smart collection's filter; rejected for manual collections.
+    pub filter: Option<GameFilter>,
+}
+
+/// Result of a batch collection membership change (see `BatchFailure` for the convention).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionBatchResult {
+    pub collection: Collection,
+    pub failures: Vec<BatchFailure>,
+    pub rolled_back: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaylistFormat {
+    /// RetroArch `.lpl` JSON playlist.
+    RetroArchLpl,
+    /// EmulationStation custom collection (`custom-<name>.cfg`, one path per line).
+    EsCollection,
+    /// Plain `.m3u`, one path per line (multi-disc games expand to every disc).
+    M3u,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistExportResult {
+    pub path: String,
+    pub format: PlaylistFormat,
+    pub exported: u64,
+    /// Games left out because required fields (path, platform mapping) were missing.
+    pub skipped_game_ids: Vec<i64>,
+    pub warnings: Vec<String>,
 }
 
 // ---- Device / Destination types ----
@@ -245,7 +773,9 @@ pub struct CollectionUpdate {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DetectedDevice {
-    /// Stable-ish identifier (platform-specific), e.g. volume UUID or device path hash.
+    /// Stable identifier. Reuses the `destination_id` of a matched known destination;
+    /// otherwise derived from the strongest available signal (partition UUID, then
+    /// filesystem serial), never from the device path.
     pub id: String,
     pub label: Option<String>,
     pub mount_points: Vec<String>,
@@ -253,6 +783,49 @@ pub struct DetectedDevice {
     pub total_bytes: Option<u64>,
     pub free_bytes: Option<u64>,
     pub is_removable: Option<bool>,
+    pub signals: DeviceSignals,
+    /// Best match against previously-seen destinations, if the score cleared the threshold.
+    pub matched: Option<DeviceMatch>,
+    pub transport: DeviceTransport,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DeviceTransport {
+    /// Mounted volume; `mount_points` are local paths.
+    Volume,
+    /// Android device over adb. `mount_points` are `adb://<serial>/<storage path>` URLs
+    /// (internal `/storage/emulated/0` plus any SD card under `/storage/<uuid>`), and the
+    /// byte counts come from `df` on the device.
+    Adb { serial: String, model: Option<String> },
+}
+
+/// Raw identity signals read from the OS for a detected volume.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceSignals {
+    pub partition_uuid: Option<String>,
+    pub fs_serial: Option<String>,
+    pub label: Option<String>,
+    pub total_bytes: Option<u64>,
+    /// Informational only (e.g. `/dev/sdb1`); not used for matching since it is unstable.
+    pub device_path: Option<String>,
+}
+
+/// Weighted match of a detected device to a known destination.
+///
+/// Weights: partition UUID 0.50, filesystem serial 0.30, total size (within 1%) 0.15,
+/// label 0.05. Only signals present on both sides count, and the score is normalized by
+/// their total weight. A score >= 0.60 is a match; >= 0.85 is `High` confidence.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceMatch {
+    pub destination_id: String,
+    pub user_device_id: Option<i64>,
+    pub score: f32,
+    pub confidence: Confidence,
+    /// Names of the signals that agreed, e.g. `["fsSerial", "totalBytes"]`.
+    pub matched_signals: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -296,6 +869,43 @@ pub struct LayoutPaths {
     pub screenshots: Option<String>,
 }
 
+/// Expected folder structure of an OS, from the definition pack.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutTemplate {
+    pub os_id: String,
+    /// Relative to the destination root, e.g. `muOS/bios` or `roms`.
+    pub paths: LayoutPaths,
+    /// Files/folders whose presence identifies the OS, relative to the root.
+    pub markers: Vec<String>,
+    /// ROM subfolder name under `paths.roms` by platform id, e.g. `psx` -> `PS`.
+    pub rom_folders: HashMap<String, String>,
+    /// Folder names are matched case-sensitively on the device.
+    pub case_sensitive: bool,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledEmulator {
+    pub emulator: Emulator,
+    pub confidence: Confidence,
+    /// Paths/markers that were found, e.g. `RetroArch/cores/snes9x_libretro.so`.
+    pub evidence: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledEmulatorsResult {
+    pub destination_path: String,
+    pub os_id: String,
+    /// False when the OS keeps emulators off the card (e.g. in firmware); `emulators` is
+    /// then empty and the result should be treated as unknown.
+    pub emulators_on_destination: bool,
+    pub emulators: Vec<InstalledEmulator>,
+    pub notes: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ResolvedDeploymentPaths {
@@ -416,24 +1026,174 @@ pub struct ProfileUpdate {
 #[serde(rename_all = "camelCase")]
 pub struct DeploymentConfig {
     pub device_profile_id: i64,
+    /// Local path/mount point, `sftp://user@host[:port]/path` for a configured
+    /// `RemoteHost`, or `adb://<serial>/<path>` for an Android device.
     pub destination_path: String,
     pub game_ids: Vec<i64>,
     pub include_bios: bool,
     pub include_saves: bool,
     pub include_states: bool,
+    /// Deprecated: use `conflict_strategy`. Only read when that is `None`, as `Overwrite`
+    /// (true) or `Skip` (false).
     pub overwrite_existing: bool,
+    /// What to do when a different file already exists at an item's `dest_path`.
+    pub conflict_strategy: Option<ConflictStrategy>,
+    /// Apply per-platform/frontend extension rules (definition pack + user overrides) to
+    /// destination filenames. Sources are never renamed.
+    pub normalize_extensions: bool,
+    /// Deploy this collection's ROMs under `roms/<platform>/<collection-name>/` (name
+    /// sanitized for the destination filesystem). Collection members are placed only in that
+    /// folder, never duplicated at the platform root, even if also listed in `game_ids`.
+    /// Gamelist entries use the `./<collection-name>/<file>` relative path.
+    pub collection_as_folder: Option<i64>,
+    /// Compress `.cue`/`.bin`, `.gdi` and `.iso` disc games to CHD while deploying, for
+    /// platforms whose resolved emulator accepts CHD. Other platforms are copied as-is with
+    /// a plan warning.
+    pub convert_to_chd: bool,
+    /// Read each copied file back and compare its hash with the source (stored library
+    /// hash when present, else hashed during the copy). Converted items are skipped.
+    pub verify_after_copy: bool,
+    /// With `verify_after_copy`, re-copy a mismatched file once before reporting it.
+    pub retry_on_mismatch: bool,
+    /// Copy every item even if an identical file is already at its destination.
+    pub force_recopy: bool,
+    /// Files copied at once. `None` = 4. Large files are still copied in chunks by a single
+    /// worker.
+    pub copy_concurrency: Option<u32>,
+    pub sync_mode: SyncMode,
+    /// Split files over 4 GiB bound for FAT32 when the platform's resolved emulator reads a
+    /// split format (`Emulator.split_formats`). Without it, or without such an emulator,
+    /// oversized files fail validation instead.
+    pub split_oversized: bool,
+    /// Rewrite destination names the target filesystem would reject (see `NameIssue`) using
+    /// the suggested name. Sources are never renamed.
+    pub sanitize_names: bool,
+    /// How ROM files reach the destination. Non-`Copy` modes apply to local destinations
+    /// only; converted and split items, and `.cue`/`.gdi` sheets with rewritten references,
+    /// are always copied.
+    pub link_mode: LinkMode,
+    /// Write an ES/ES-DE `gamelist.xml` per deployed platform (see `create_deployment_plan`).
+    pub generate_gamelist: bool,
+    /// Deploy cover art and screenshots found for each game (see `create_deployment_plan`).
+    pub include_media: bool,
+    /// Destination media layout. `None` picks it from the profile's frontend.
+    pub media_layout: Option<MediaLayout>,
+}
+
+/// Where a frontend expects game media on the device.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaLayout {
+    /// RetroArch: `thumbnails/<playlist name>/Named_Boxarts|Named_Snaps/<label>.png`, with
+    /// `&*/:<>?\|` and backticks in the label replaced by `_`.
+    RetroarchThumbnails,
+    /// ES-DE: `ES-DE/downloaded_media/<platform>/covers|screenshots/<rom stem>.<ext>`.
+    EsdeDownloadedMedia,
+    /// Classic ES / Batocera style: `<rom folder>/media/covers|screenshots/<rom stem>.<ext>`.
+    RomFolderMedia,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkMode {
+    Copy,
+    /// Same filesystem only; otherwise copied with a plan warning.
+    Hardlink,
+    /// Absolute symlink to the library file. Works across filesystems, but falls back to
+    /// copying where the destination can't hold symlinks (FAT32/exFAT, Windows without the
+    /// privilege).
+    Symlink,
+    /// Copy-on-write clone (Btrfs, XFS, APFS, ReFS). Same filesystem only; otherwise, or
+    /// where unsupported, copied with a warning.
+    Reflink,
+}
+
+/// Handling of an existing, different file at a deployment item's destination. Identical
+/// files are skipped under every strategy unless `force_recopy` is set.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Leave the existing file.
+    Skip,
+    Overwrite,
+    /// Overwrite only when the source mtime is newer than the destination's.
+    OverwriteIfNewer,
+    /// Overwrite when size or hash differ (same comparison as incremental skipping).
+    OverwriteIfDifferentHash,
+    /// Keep the existing file and write the new one as `<stem> (1).<ext>`, `(2)`, ...
+    Rename,
+}
+
+/// Action `create_deployment_plan` chose for an item whose destination already existed.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictAction {
+    Overwrite,
+    /// See `skip_reason`.
+    Skip,
+    /// `dest_path` holds the suffixed name; `renamed_from` the name that was taken.
+    Rename,
+}
+
+/// On-disk split layouts emulators can read back as one image.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum SplitFormat {
+    /// Wii `.wbfs` + `.wbf1`, `.wbf2`, ... (4 GiB - 32 KiB parts).
+    Wbfs,
+    /// `<name>.part0`, `<name>.part1`, ... (4 GiB - 1 parts).
+    Parts,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncMode {
+    /// Only add/overwrite; nothing at the destination is removed. Default.
+    Additive,
+    /// Also delete files under the resolved `roms` path that are not part of the plan.
+    /// `bios`, saves, states and anything outside `roms` are never deleted, since saves may
+    /// hold progress made on the device.
+    Mirror,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DeploymentPlan {
+    /// Deterministic plan id: `sha256(config_hash || content_hash)`, hex-encoded.
+    ///
+    /// Identical configs against an unchanged library/destination yield the same id, so
+    /// plan creation is idempotent and the id can be used for dedupe, caching and resume.
     pub plan_id: String,
+    /// `sha256` of the whole normalized `DeploymentConfig` as canonical JSON (keys sorted,
+    /// every field present). Normalization:
+    /// - `destinationPath`: absolute, separators normalized, no trailing slash
+    /// - `gameIds`: sorted + deduped
+    /// - `conflictStrategy`: `None` resolved from `overwriteExisting`
+    /// - `copyConcurrency`, `mediaLayout`: `None` resolved to the effective default
+    ///
+    /// Every other field (e.g. `syncMode`, `linkMode`, `collectionAsFolder`) is hashed
+    /// as-is, so plans differing in any option get different ids.
+    pub config_hash: String,
+    /// `sha256` over the ordered items as `kind|source_path|dest_path|bytes` lines.
+    /// When the library or destination changes, the plan is rebuilt and gets a new id.
+    pub content_hash: String,
     pub device_profile_id: i64,
     pub destination_path: String,
     pub resolved_paths: Option<ResolvedDeploymentPaths>,
     pub items: Vec<DeploymentItem>,
+    /// Items that will actually be written (skipped items excluded).
     pub total_files: u64,
+    /// Bytes that will actually be written (skipped items excluded).
     pub total_bytes: u64,
+    /// Items already present and identical at the destination.
+    pub skipped_files: u64,
+    /// `total_bytes / throughput + total_files * per_file_overhead`, from the device's
+    /// latest measured deployment (`DeploymentRecord`), so many small files estimate
+    /// slower than one large file of the same size.
+    pub estimated_secs: u64,
+    /// False when the device has no measured deployment yet and conservative defaults
+    /// (10 MiB/s, 20 ms per file) were used.
+    pub estimate_measured: bool,
     pub warnings: Vec<String>,
 }
 
@@ -446,60 +1206,309 @@ pub struct DeploymentItem {
     pub bytes: u64,
     pub platform_id: Option<String>,
     pub game_id: Option<i64>,
+    /// Original filename when the destination name was changed (e.g. extension normalized).
+    pub renamed_from: Option<String>,
+    /// Target format when the item is converted during deployment (`chd`). `bytes` is then
+    /// the estimated compressed size, and `source_path` is the disc's primary file (its
+    /// tracks are folded into the one output).
+    pub convert_to: Option<String>,
+    /// Set when the item is kept in the plan for display but will not be copied.
+    pub skip_reason: Option<SkipReason>,
+    /// Set when the file is written as split parts (`split_oversized`). `dest_path` is the
+    /// first part; `bytes` stays the full size.
+    pub split: Option<SplitFormat>,
+    /// Set when something already existed at `dest_path`.
+    pub conflict: Option<ConflictAction>,
+    /// Set when the item will be linked rather than copied; `bytes` is then 0 since
+    /// nothing is transferred. `None` = copied.
+    pub link: Option<LinkMode>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
-pub enum DeploymentItemKind {
-    Rom,
-    Bios,
-    Save,
-    State,
-    Media,
-    Metadata,
-    Other,
+pub enum DiffChange {
+    /// Nothing at `dest_path` yet.
+    New,
+    /// A different file is at `dest_path`.
+    Overwrite,
+    /// Same size and hash already at `dest_path`.
+    Identical,
+    /// Removed by mirror sync.
+    Delete,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct ValidationResult {
-    pub valid: bool,
-    pub errors: Vec<String>,
-    pub warnings: Vec<String>,
+pub struct DiffEntry {
+    pub item: DeploymentItem,
+    pub change: DiffChange,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct DeploymentHandle {
-    pub handle_id: String,
+pub struct DiffTotals {
+    pub files: u64,
+    pub bytes: u64,
 }
 
+/// Result of `deploy::preview_deployment`: what a plan would change at the destination.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "snake_case")]
-pub enum DeploymentStatus {
-    Running,
-    Paused,
-    Cancelled,
-    Completed,
-    Failed,
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentDiff {
+    pub plan_id: String,
+    pub entries: Vec<DiffEntry>,
+    pub new: DiffTotals,
+    pub overwrite: DiffTotals,
+    pub identical: DiffTotals,
+    pub delete: DiffTotals,
 }
 
+/// `.romrunner-manifest.json` in a destination root: everything Rom Runner has deployed
+/// there. Rewritten at the end of each deployment.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct DeploymentRecord {
-    pub id: String,
-    pub device_id: i64,
+pub struct DeploymentManifest {
+    /// Format version, currently 1.
+    pub version: u32,
     pub device_profile_id: i64,
-    pub started_at: String,
-    pub finished_at: Option<String>,
-    pub status: DeploymentStatus,
-    pub total_files: u64,
-    pub total_bytes: u64,
-    pub errors: Vec<String>,
-    pub warnings: Vec<String>,
+    pub plan_id: String,
+    pub deployed_at: String,
+    pub entries: Vec<ManifestEntry>,
 }
 
-// ---- BIOS types (aligned to ROM_Runner_JSON_Schemas_v1_1_0) ----
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestEntry {
+    pub game_id: Option<i64>,
+    pub title: Option<String>,
+    pub kind: DeploymentItemKind,
+    /// Relative to the destination root, `/`-separated.
+    pub relative_path: String,
+    pub size: u64,
+    pub hash: Option<String>,
+    pub hash_algorithm: Option<HashAlgorithm>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// A file at `dest_path` already has the same size and hash as the source.
+    Identical,
+    /// `ConflictStrategy::Skip` kept the existing file.
+    Exists,
+    /// `ConflictStrategy::OverwriteIfNewer` kept a destination file at least as new.
+    NotNewer,
+}
+
+/// Magic bytes identifying a platform from a file header, from the definition pack.
+///
+/// A signature matches when `bytes_hex` appears at `offset` (or anywhere in the first
+/// `search_window` bytes when set). Only the first 64 KiB of a file are ever read.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderSignature {
+    pub platform_id: String,
+    pub offset: u64,
+    /// e.g. `4E45531A` for iNES (`NES\x1a`).
+    pub bytes_hex: String,
+    pub search_window: Option<u64>,
+    /// Confidence reported when this signature decides the platform.
+    pub confidence: Confidence,
+    /// Limits the signature to these extensions (no dot); `None` tries it on every file.
+    pub extensions: Option<Vec<String>>,
+}
+
+/// Destination extension rewrite, e.g. `smc` -> `sfc` for SNES on a given frontend.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionRule {
+    pub platform_id: String,
+    /// `None` applies to every frontend.
+    pub frontend_id: Option<String>,
+    /// Source extension without the dot, matched case-insensitively.
+    pub from: String,
+    /// Exact destination extension (case preserved, so `CUE` is allowed).
+    pub to: String,
+}
+
+/// Destination filename transforms for a frontend and/or OS, from the definition pack.
+///
+/// `create_deployment_plan` picks the most specific rule for the profile (frontend + OS,
+/// then frontend, then OS, then a rule with neither) and applies its transforms in order to
+/// the file stem, after extension rules. No matching rule means names are kept as-is.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NameRule {
+    pub frontend_id: Option<String>,
+    pub os_id: Option<String>,
+    pub transforms: Vec<NameTransform>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NameTransform {
+    /// Remove parenthesized region groups, e.g. `(USA)` or `(Europe, Japan)`.
+    StripRegionTags,
+    Lowercase,
+    /// Replace characters FAT/exFAT reject (`"*/:<>?\|`) with `replacement`.
+    ReplaceIllegalChars { replacement: String },
+    /// Append a disc token to multi-disc members; `{n}` is the 1-based disc number,
+    /// e.g. ` (Disc {n})`. Single-disc games are left alone.
+    AppendDiscToken { format: String },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentItemKind {
+    Rom,
+    Bios,
+    Save,
+    State,
+    Media,
+    Metadata,
+    Other,
+    /// Planned removal of `dest_path` (mirror mode); `source_path` is empty and `bytes` is
+    /// the size being freed.
+    Delete,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationResult {
+    pub valid: bool,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+    /// Result of the create+delete probe at the resolved ROM path, when one was performed.
+    pub write_test: Option<WriteTestResult>,
+    /// Destination names the target filesystem would reject. Each is also listed in
+    /// `warnings`.
+    pub name_issues: Vec<NameIssue>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum NameIssueKind {
+    /// Characters FAT32/exFAT reject: `"*/:<>?\|` and control characters.
+    IllegalChars,
+    /// Trailing dot or space, which FAT drivers silently strip.
+    TrailingDotOrSpace,
+    /// Windows reserved device name (`CON`, `NUL`, `COM1`, ...).
+    ReservedName,
+    /// A path component over 255 UTF-16 units.
+    NameTooLong,
+    /// Full path over the destination's limit (260 for FAT32 under Windows-style hosts).
+    PathTooLong,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NameIssue {
+    pub dest_path: String,
+    pub game_id: Option<i64>,
+    pub kind: NameIssueKind,
+    /// Sanitized `dest_path`, made unique against the rest of the plan.
+    pub suggested: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteTestStatus {
+    Writable,
+    ReadOnlyFilesystem,
+    PermissionDenied,
+    /// Stale mountpoint or the device disappeared.
+    DeviceNotConnected,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteTestResult {
+    pub path: String,
+    pub status: WriteTestStatus,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentHandle {
+    pub handle_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentStatus {
+    Running,
+    Paused,
+    Cancelled,
+    Completed,
+    Failed,
+    /// Stopped by a crash, quit or unplug; see `DeploymentRecord.resumable`.
+    Interrupted,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentRecord {
+    pub id: String,
+    pub device_id: i64,
+    pub device_profile_id: i64,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub status: DeploymentStatus,
+    pub total_files: u64,
+    pub total_bytes: u64,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+    /// True while a journal exists for this deployment (`id` is its handle id).
+    pub resumable: bool,
+    /// True until the deployment is confirmed, rolled back, or the window expires.
+    pub rollback_available: bool,
+    pub rollback_expires_at: Option<String>, // ISO 8601
+    /// Measured on completion: bytes over time spent copying, excluding per-file overhead.
+    pub throughput_bps: Option<u64>,
+    /// Measured on completion: fixed cost per file (open/create/close, metadata).
+    pub per_file_overhead_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueEntry {
+    pub handle_id: String,
+    pub plan_id: String,
+    pub device_profile_id: i64,
+    pub destination_path: String,
+    pub status: QueueStatus,
+    /// 0-based among pending entries; `None` once started.
+    pub position: Option<u32>,
+    pub enqueued_at: String,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackResult {
+    pub handle_id: String,
+    /// Files the deployment created, now removed.
+    pub removed: u64,
+    /// Overwritten or mirror-deleted files put back from backup.
+    pub restored: u64,
+    /// Paths that could not be reverted, with the reason.
+    pub errors: Vec<String>,
+}
+
+// ---- BIOS types (aligned to ROM_Runner_JSON_Schemas_v1_1_0) ----
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -596,6 +1605,16 @@ pub struct BiosVerificationResult {
     pub matched_bios_id: Option<String>,
     pub matched_bios_name: Option<String>,
     pub matched_platform: Option<String>,
+    /// `verify_all_bios` only: path relative to the scanned directory, `/`-separated.
+    pub relative_path: Option<String>,
+    /// Set on results for a file inside a `.zip`/`.7z`; `path` is then `archive.zip#entry`.
+    pub archive_entry: Option<String>,
+    /// `verify_bios_file` on an archive: one result per file entry. The outer result is
+    /// `Present` if any entry matched, else `Unknown`, and carries no hashes.
+    pub entries: Option<Vec<BiosVerificationResult>>,
+    /// Whether the file sits in the matched entry's `bios_subdirectory` (or one of its
+    /// `alternate_subdirectories`); `None` when nothing matched or no subdirectory is set.
+    pub in_expected_subdirectory: Option<bool>,
     pub notes: String,
 }
 
@@ -619,10 +1638,39 @@ pub struct BiosVerificationSummary {
     pub total: u64,
 }
 
+/// A file whose hash matches a known BIOS but whose name doesn't match `BiosFile.filename`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BiosRenameSuggestion {
+    pub from_path: String,
+    /// Same directory, canonical `BiosFile.filename`.
+    pub to_path: String,
+    pub bios_id: String,
+    pub bios_name: String,
+    /// Only the letter case differs (`scph5501.BIN` -> `scph5501.bin`).
+    pub case_only: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BiosRenameFailure {
+    pub from_path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BiosRenameResult {
+    pub renamed: Vec<BiosRenameSuggestion>,
+    pub failures: Vec<BiosRenameFailure>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct BiosRequirement {
     pub platform_id: String,
+    /// Emulator the requirement was resolved for (`get_bios_requirements_for_device`).
+    pub emulator_id: Option<String>,
     pub required: Vec<BiosFileRef>,
     pub optional: Vec<BiosFileRef>,
 }
@@ -635,6 +1683,9 @@ pub struct BiosFileRef {
     pub filename: String,
     pub region: Option<Region>,
     pub notes: Option<String>,
+    /// Emulators whose `required_for_emulators`/`optional_for_emulators` pulled this file
+    /// in, when requirements were resolved per emulator.
+    pub emulator_ids: Option<Vec<String>>,
 }
 
 /// Report describing BIOS completeness for a set of platforms.
@@ -646,6 +1697,12 @@ pub struct BiosCompletenessReport {
     pub missing_required: Vec<BiosFileRef>,
     pub missing_optional: Vec<BiosFileRef>,
     pub present: Vec<BiosFileRef>,
+    /// Required entries counted as satisfied only through `BiosFile.hle_fallback`.
+    pub satisfied_by_hle: Vec<BiosFileRef>,
+    /// Satisfied required slots over all required slots, 0-100. A slot is one required
+    /// entry, or a group of region variants of the same BIOS. `100.0` when nothing is
+    /// required.
+    pub completeness_pct: f32,
     pub notes: Vec<String>,
 }
 
@@ -676,6 +1733,9 @@ pub struct GamePerformance {
     pub notes: Option<String>,
     pub exclude_from_auto_lists: Option<bool>,
     pub source: Option<String>,
+    /// Lower-priority rows for the same game/chipset/emulator, in priority order. Only
+    /// set on results of the single-record lookup.
+    pub alternatives: Option<Vec<GamePerformance>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -701,6 +1761,28 @@ pub struct EmulatorCompat {
     pub source: Option<String>,
     pub source_url: Option<String>,
     pub last_updated: Option<String>,
+    /// Lower-priority rows for the same game/emulator, in priority order. Only set on
+    /// results of the single-record lookup.
+    pub alternatives: Option<Vec<EmulatorCompat>>,
+}
+
+/// A `source` value seen in the loaded compatibility data. Rows without one are `pack`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatSource {
+    pub source: String,
+    pub performance_rows: u64,
+    pub compat_rows: u64,
+    /// Index in `AppSettings.compat_source_priority`; `None` if unlisted.
+    pub priority: Option<u32>,
+}
+
+/// Everything recorded through `compat::set_user_compat` / `set_user_performance`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserCompatEntries {
+    pub compat: Vec<EmulatorCompat>,
+    pub performance: Vec<GamePerformance>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -722,6 +1804,74 @@ pub struct RefreshResult {
     pub performance_rows: u64,
     pub compat_rows: u64,
     pub settings_rows: u64,
+    /// True when the tables came from the on-disk cache instead of re-parsing the pack.
+    pub loaded_from_disk: bool,
+    /// `DefinitionPackMeta.version` the tables belong to.
+    pub pack_version: Option<String>,
+}
+
+/// Weights `compat::recommend_emulator` combines into a 0-1 score. Each signal is first
+/// mapped to 0-1; a missing signal counts as 0.5.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendationWeights {
+    /// `PerformanceTier` on the device's chipset: unplayable 0 ... excellent 1.
+    pub performance: f32,
+    /// `CompatStatus`: broken 0, boots_only 0.1, menu_intro 0.2, ingame 0.5, playable 0.8,
+    /// perfect 1.
+    pub compat: f32,
+    /// 1 for the OS profile's default emulator for the platform, else 0.
+    pub os_default: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum RecommendationSource {
+    /// `GameOverride.force_emulator_id`.
+    GameOverride,
+    PlatformOverride,
+    Scored,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EmulatorScore {
+    pub emulator_id: String,
+    pub score: f32,
+    pub performance_tier: Option<PerformanceTier>,
+    pub compat_status: Option<CompatStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EmulatorRecommendation {
+    pub game_id: String,
+    pub emulator_id: String,
+    pub source: RecommendationSource,
+    /// One-line explanation, e.g. "Good on Snapdragon 865, playable in compat reports".
+    pub rationale: String,
+    /// Every candidate, best first, including the pick (scored even when overridden).
+    pub candidates: Vec<EmulatorScore>,
+    pub weights: RecommendationWeights,
+}
+
+/// Launch command for a game on a device, as the target frontend would run it.
+///
+/// `executable` and `args` may contain on-device placeholders that are substituted at
+/// launch time (or when writing gamelists/launch scripts):
+/// `{rom_path}`, `{rom_dir}`, `{rom_name}`, `{core_path}`, `{core_name}`.
+/// `placeholders` carries the values ROM Runner resolved from the deployment paths.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchCommand {
+    pub game_id: i64,
+    pub device_profile_id: i64,
+    pub emulator_id: String,
+    pub core_name: Option<String>,
+    pub executable: String,
+    pub args: Vec<String>,
+    /// Placeholder (including braces) -> resolved on-device value.
+    pub placeholders: HashMap<String, String>,
 }
 
 // ---- Settings types ----
@@ -733,6 +1883,61 @@ pub struct ScanSettings {
     pub trust_detected_layout_over_expected: bool,
     pub remember_scanned_layouts: bool,
     pub remember_user_path_overrides: bool,
+    /// Filename flag -> tag mapping applied on import. `None` uses the built-in mapping:
+    /// `(Proto)` -> prototype, `(Beta)` -> beta, `(Unl)` -> unlicensed,
+    /// `[T+...]`/`[T-...]` -> translation, `[h]` -> hack (sets `is_hack`), `[b]` -> bad_dump.
+    pub auto_tag_rules: Option<Vec<AutoTagRule>>,
+    /// Delete persisted scan results older than this many days. `None` keeps everything.
+    pub scan_history_retention_days: Option<u32>,
+    /// Gitignore-style globs matched against paths relative to the library root
+    /// (case-insensitive, `**` supported). A pattern without `/` matches at any depth; a
+    /// trailing `/` matches directories only (e.g. `Imgs/`, `.media/`). A matching directory
+    /// is pruned with its whole subtree before anything beneath it is read or hashed.
+    /// Seeded with: `**/.DS_Store`, `**/._*`, `**/Thumbs.db`, `**/desktop.ini`,
+    /// `**/*.txt`, `**/*.nfo`, `**/$RECYCLE.BIN/**`, `**/.Trashes/**`.
+    pub exclude_globs: Vec<String>,
+    /// Files hashed in parallel during a scan. `None` uses the CPU count. Reads still draw
+    /// from `AppSettings.io_read_budget`, so the effective limit is the smaller of the two.
+    pub hash_concurrency: Option<u32>,
+    /// While the library watcher runs, rescan changed paths after each `library_changed`
+    /// event (only those paths, reported through the usual scan events). `None` = off.
+    pub auto_rescan_on_change: Option<bool>,
+    /// Quiet period before buffered watcher events are emitted. `None` = 2000 ms.
+    pub watch_debounce_ms: Option<u32>,
+}
+
+/// Maps a filename flag to an auto tag.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoTagRule {
+    /// Flag text without brackets, matched case-insensitively as a prefix of a `(...)` or
+    /// `[...]` group (so `T+` matches `[T+Eng]`).
+    pub flag: String,
+    pub tag: String,
+    pub sets_is_hack: bool,
+}
+
+/// An SSH/SFTP host that `sftp://` destinations may use.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteHost {
+    pub host: String,
+    /// Default: 22.
+    pub port: Option<u16>,
+    pub username: String,
+    pub auth: RemoteAuth,
+    /// Trusted host key fingerprint (`SHA256:...`), set by `trust_host_key`.
+    pub host_key_fingerprint: Option<String>,
+}
+
+/// Secrets are never stored in settings; passwords and key passphrases live in the OS
+/// keychain (`set_remote_secret`).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum RemoteAuth {
+    Agent,
+    KeyFile { path: String },
+    Password,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -742,6 +1947,46 @@ pub struct AppSettings {
     pub default_bios_dir: Option<String>,
     pub definition_pack_path: Option<String>,
     pub scan_settings: ScanSettings,
+    /// Global cap on concurrent file reads shared by scanning, BIOS verification,
+    /// deployment verification and hashing. Each operation holds one permit per in-flight
+    /// file, so overlapping operations never exceed this total. Default: 4.
+    pub io_read_budget: u32,
+    /// User extension rules; these win over pack rules with the same platform/frontend/from.
+    pub extension_rule_overrides: Vec<ExtensionRule>,
+    /// Default region order for 1G1R selection, duplicate tie-breaks and region-aware BIOS
+    /// inference. Commands taking an explicit region list use it instead of this default.
+    pub region_priority: Vec<Region>,
+    /// Default ISO 639-1 language order, applied the same way as `region_priority`.
+    pub language_priority: Vec<String>,
+    /// Minimum level written to the log file. Changes apply immediately, no restart.
+    pub log_level: LogLevel,
+    /// Scratch directory for conversions (e.g. CHD). `None` uses the OS temp dir.
+    pub temp_dir: Option<String>,
+    /// How long a deployment can be rolled back; backups are purged afterwards. `0` keeps
+    /// no backups (no rollback). Default: 72.
+    pub rollback_window_hours: u32,
+    /// Hosts for `sftp://user@host[:port]/path` destinations, matched on host + user.
+    pub remote_hosts: Vec<RemoteHost>,
+    /// `adb` executable. `None` searches `PATH` and the Android SDK default locations.
+    pub adb_path: Option<String>,
+    /// Local saves/states, laid out as `<dir>/<platform_id>/<file>`: the source for
+    /// `include_saves`/`include_states` and the target of `pull_saves`. `None` uses
+    /// `<app data>/saves`.
+    pub local_saves_dir: Option<String>,
+    /// Local media library, laid out as `<dir>/<platform_id>/covers|screenshots/<file>`.
+    /// `None` disables folder lookup; paths stored in game metadata still work.
+    pub media_dir: Option<String>,
+    /// Queued deployments run at once, on distinct physical devices only. Default: 1.
+    pub queue_concurrency: u32,
+    /// User download URLs by BIOS id, for `download_missing_bios`. Used before the
+    /// pack's `BiosHash.source_url`.
+    pub bios_source_urls: HashMap<String, String>,
+    /// Compatibility `source` values, highest priority first. When several rows describe
+    /// the same record, the first listed source wins; unlisted sources follow, by name.
+    /// `user` ranks first unless it is listed explicitly.
+    pub compat_source_priority: Vec<String>,
+    /// Pack update manifest (`https://` only). `None` uses the official manifest.
+    pub pack_update_url: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -751,6 +1996,25 @@ pub struct SettingsUpdate {
     pub default_bios_dir: Option<String>,
     pub definition_pack_path: Option<String>,
     pub scan_settings: Option<ScanSettings>,
+    /// Must be >= 1. Takes effect for newly acquired permits; in-flight reads finish as-is.
+    pub io_read_budget: Option<u32>,
+    pub extension_rule_overrides: Option<Vec<ExtensionRule>>,
+    /// Rejected with `InvalidInput` if it contains duplicates, `Unknown` or `universal`.
+    pub region_priority: Option<Vec<Region>>,
+    pub language_priority: Option<Vec<String>>,
+    pub log_level: Option<LogLevel>,
+    pub temp_dir: Option<String>,
+    pub rollback_window_hours: Option<u32>,
+    pub remote_hosts: Option<Vec<RemoteHost>>,
+    pub adb_path: Option<String>,
+    pub local_saves_dir: Option<String>,
+    pub media_dir: Option<String>,
+    /// Must be >= 1. Applies when the next queued entry is picked.
+    pub queue_concurrency: Option<u32>,
+    /// Replaces the whole map. Only `https://` URLs are accepted.
+    pub bios_source_urls: Option<HashMap<String, String>>,
+    pub compat_source_priority: Option<Vec<String>>,
+    pub pack_update_url: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -770,34 +2034,308 @@ pub enum OverrideAction {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct GameOverride {
-    pub game_id: i64,
-    pub action: OverrideAction,
-    pub force_emulator_id: Option<String>,
+pub struct GameOverride {
+    pub game_id: i64,
+    pub action: OverrideAction,
+    pub force_emulator_id: Option<String>,
+    pub notes: Option<String>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPathOverrideEntry {
+    pub destination_id: String,
+    pub os_id: String,
+    pub last_scanned: String,
+    pub path_overrides: LayoutPaths,
+    pub notes: Option<String>,
+}
+
+// ---- Logging ----
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+/// One structured entry from the rotating log file in the app data dir.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    pub timestamp: String, // ISO 8601
+    pub level: LogLevel,
+    /// Module/command that emitted the entry, e.g. `commands::deploy`.
+    pub target: String,
+    pub message: String,
+    /// Structured span/event fields (command name, ids, paths).
+    pub fields: Option<JsonValue>,
+}
+
+// ---- Database schema ----
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingMigration {
+    pub version: u32,
+    /// Script name, e.g. `migration_v1_0_1_to_v1_0_2`.
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaStatus {
+    pub current_version: u32,
+    pub target_version: u32,
+    pub pending: Vec<PendingMigration>,
+    /// Error from the last startup migration run, if it failed.
+    pub last_error: Option<String>,
+    /// Pre-migration snapshot taken before the last migration run.
+    pub last_backup_path: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseBackup {
+    pub path: String,
+    pub bytes: u64,
+    pub schema_version: u32,
+    pub created_at: String,
+}
+
+/// Summary of a file written by `settings::export_settings`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsExport {
+    pub path: String,
+    pub bundle_version: u32,
+    pub platform_overrides: u64,
+    pub game_overrides: u64,
+    pub path_overrides: u64,
+    /// Game overrides left out because the game has no stored hash to match it by.
+    pub skipped_game_ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingsSection {
+    AppSettings,
+    PlatformOverrides,
+    GameOverrides,
+    PathOverrides,
+}
+
+/// An imported value that was not applied, or was applied but may not work here.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsImportIssue {
+    pub section: SettingsSection,
+    /// Setting name, platform id, game hash or destination id.
+    pub key: String,
+    pub message: String,
+    pub applied: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsImportResult {
+    /// Version found in the file; older bundles are migrated before applying.
+    pub bundle_version: u32,
+    pub settings: AppSettings,
+    pub platform_overrides: u64,
+    pub game_overrides: u64,
+    pub path_overrides: u64,
+    pub issues: Vec<SettingsImportIssue>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsPreset {
+    pub name: String,
+    pub settings: AppSettings,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+// ---- Diagnostics ----
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Ok,
+    Degraded,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubsystemHealth {
+    /// e.g. `database`, `definition_pack`, `archives`, `hashing`, `app_data_dir`.
+    pub name: String,
+    pub status: HealthStatus,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveTask {
+    /// `scan` or `deployment`.
+    pub kind: String,
+    pub id: String,
+    pub started_at: String,
+}
+
+/// Backend health snapshot for bug reports.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostics {
+    pub generated_at: String,
+    pub app_version: String,
+    /// `None` when the database is unreachable.
+    pub schema_version: Option<u32>,
+    pub target_schema_version: u32,
+    pub definition_pack: Option<DefinitionPackMeta>,
+    pub app_data_dir: String,
+    pub app_data_free_bytes: Option<u64>,
+    /// Supported archive formats, e.g. `["zip", "7z"]`.
+    pub archive_formats: Vec<String>,
+    pub hash_algorithms: Vec<HashAlgorithm>,
+    pub active_tasks: Vec<ActiveTask>,
+    pub subsystems: Vec<SubsystemHealth>,
+}
+
+// ---- Definition pack types (subset of ROM_Runner_JSON_Schemas_v1_1_0) ----
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DefinitionPackMeta {
+    /// Version fields are the base (first) layer's.
+    pub version: String,
+    pub schema_version: String,
+    pub release_date: String,
+    pub min_app_version: String,
+    /// Top (last) layer's path; `None` for the bundled default.
+    pub loaded_from: Option<String>,
+    /// Pack paths in layer order; empty for the bundled default alone.
+    pub layers: Vec<String>,
+    /// Overrides between layers that look accidental.
+    pub collisions: Vec<PackCollision>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PackEntityType {
+    Platform,
+    Emulator,
+    Chipset,
+    Device,
+    OperatingSystem,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PackChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// One changed field of a modified entity. `None` means absent on that side.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PackFieldChange {
+    /// JSON pointer relative to the entity, e.g. `/aliases` or `/links/0`.
+    pub pointer: String,
+    pub old: Option<JsonValue>,
+    pub new: Option<JsonValue>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PackEntityChange {
+    pub entity_type: PackEntityType,
+    pub id: String,
+    pub name: String,
+    pub kind: PackChangeKind,
+    /// Empty unless `kind` is `Modified`.
+    pub fields: Vec<PackFieldChange>,
+}
+
+/// Result of `definitions::diff_packs`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PackDiff {
+    pub old_version: String,
+    pub new_version: String,
+    pub old_schema_version: String,
+    pub new_schema_version: String,
+    /// Fields present in only one schema version, as `<entityType>/<field>`. They were not
+    /// compared.
+    pub skipped_fields: Vec<String>,
+    /// Sorted by entity type, then id.
+    pub changes: Vec<PackEntityChange>,
+}
+
+/// Latest pack on an update channel, from `definitions::check_for_pack_update`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PackUpdateInfo {
+    pub channel: String,
+    pub version: String,
+    pub schema_version: String,
+    pub release_date: String,
+    pub min_app_version: String,
+    /// `min_app_version` and `schema_version` are supported by this build. An incompatible
+    /// update is still reported so the UI can ask for an app update.
+    pub compatible: bool,
+    pub url: String,
+    pub size_bytes: u64,
+    pub sha256: String,
     pub notes: Option<String>,
-    pub updated_at: String,
 }
 
+/// A later layer redefining an entity in a way that looks unintended: its `name` or
+/// `manufacturer` differs from the earlier one, or its id differs only in case/punctuation
+/// (`rg35xx-plus` vs `RG35XX_Plus`). The merge still applies; this is informational.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct UserPathOverrideEntry {
-    pub destination_id: String,
-    pub os_id: String,
-    pub last_scanned: String,
-    pub path_overrides: LayoutPaths,
-    pub notes: Option<String>,
+pub struct PackCollision {
+    /// Pack array the entity lives in, e.g. `devices`.
+    pub entity_type: String,
+    pub id: String,
+    /// Paths of the layers involved, earliest first.
+    pub sources: Vec<String>,
+    pub reason: String,
 }
 
-// ---- Definition pack types (subset of ROM_Runner_JSON_Schemas_v1_1_0) ----
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PackIssue {
+    /// Pack file the issue is in, relative to the pack root.
+    pub file: String,
+    /// JSON pointer within `file`, e.g. `/emulators/12/platforms/0`.
+    pub pointer: String,
+    pub message: String,
+}
 
+/// Result of `definitions::validate_definition_pack`.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct DefinitionPackMeta {
-    pub version: String,
-    pub schema_version: String,
-    pub release_date: String,
-    pub min_app_version: String,
-    pub loaded_from: Option<String>,
+pub struct PackValidationReport {
+    pub path: String,
+    /// Declared `schemaVersion`; `None` if missing (itself an error).
+    pub schema_version: Option<String>,
+    /// No errors. Warnings don't block loading.
+    pub valid: bool,
+    /// Malformed JSON, missing required fields, wrong types, dangling references.
+    pub errors: Vec<PackIssue>,
+    /// Unknown fields, duplicate aliases, entities nothing references.
+    pub warnings: Vec<PackIssue>,
 }
 
 // These mirror schema shapes at a high-level for IPC.
@@ -819,10 +2357,14 @@ pub struct Emulator {
     pub id: String,
     pub name: String,
     pub kind: Option<String>,
+    /// Libretro core name for `libretro-core` emulators (e.g. `snes9x_libretro`).
+    pub core_name: Option<String>,
     pub platforms: Option<Vec<String>>,
     pub website: Option<String>,
     pub repository: Option<String>,
     pub status: Option<String>,
+    /// Split layouts this emulator loads; `None` = none.
+    pub split_formats: Option<Vec<SplitFormat>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -873,6 +2415,75 @@ pub struct Frontend {
     pub metadata_format: Option<String>,
 }
 
+/// Where a definition-pack default emulator came from.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum DefaultEmulatorSource {
+    /// `osEmulatorProfiles.profilesByOsId[os].defaults.platformToEmulator`.
+    OsProfile,
+    /// `osEmulatorProfiles.fallbackDefaults.platformDefaults`.
+    FallbackDefaults,
+    /// First emulator supporting the platform with `status == "active"`.
+    FirstSupporting,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DefaultEmulator {
+    pub platform_id: String,
+    pub os_id: String,
+    pub emulator: Emulator,
+    pub source: DefaultEmulatorSource,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PlatformVerdict {
+    /// >= 80% of rated games are `playable` or better.
+    Yes,
+    /// >= 30%.
+    Partial,
+    No,
+    /// Fewer than 5 rated games.
+    Unknown,
+}
+
+/// One cell of the "can this chipset run X" matrix.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChipsetPlatformCell {
+    pub platform_id: String,
+    pub verdict: PlatformVerdict,
+    /// Games with a `GamePerformance` row for this chipset, best emulator per game.
+    pub games_rated: u32,
+    /// Share of `games_rated` at `playable` or better, 0-100.
+    pub playable_pct: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChipsetComparisonRow {
+    /// Carries `cpu_cores`, `cpu_arch`, `gpu`, `performance_tier`, `max_platform_tier`.
+    pub chipset: Chipset,
+    /// Mean `playable_pct` over platforms with a verdict other than `Unknown`; `None`
+    /// without any.
+    pub score: Option<f32>,
+    /// Catalog devices built on this chipset.
+    pub device_ids: Vec<String>,
+    /// Same order as `ChipsetComparison.platform_ids`.
+    pub cells: Vec<ChipsetPlatformCell>,
+}
+
+/// Result of `definitions::compare_chipsets`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChipsetComparison {
+    /// Matrix columns: every platform with ratings for at least one compared chipset.
+    pub platform_ids: Vec<String>,
+    /// Highest `score` first; rows without a score last, by `performance_tier`.
+    pub rows: Vec<ChipsetComparisonRow>,
+}
+
 // ---- File System types ----
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -882,7 +2493,7 @@ pub struct FileFilter {
     pub extensions: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum HashAlgorithm {
     Md5,
@@ -906,8 +2517,31 @@ pub struct DirectoryInfo {
 pub struct CopyResult {
     pub source: String,
     pub dest: String,
+    /// Logical size of the destination file.
     pub bytes: u64,
     pub overwritten: bool,
+    /// True when long zero runs were written as holes instead of data.
+    pub sparse: bool,
+    /// Bytes actually allocated on the destination; only set when `sparse` is true.
+    pub physical_bytes: Option<u64>,
+    /// False when an overwrite could not use temp-file + rename and wrote in place instead.
+    pub atomic: bool,
+    pub warnings: Vec<String>,
+    /// Source hash computed while copying, when `CopyOptions.verify_hash` was set.
+    pub hash: Option<String>,
+    /// Whether the read-back destination matched `hash`; `None` when not verified.
+    pub verified: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyOptions {
+    /// Enables `copy_progress` events and `cancel_copy` for this copy.
+    pub operation_id: Option<String>,
+    /// Chunk size in bytes. Default: 1 MiB.
+    pub buffer_size: Option<u32>,
+    /// Hash the source while copying, then read the destination back and compare.
+    pub verify_hash: Option<HashAlgorithm>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -925,6 +2559,9 @@ pub struct ArchiveEntry {
     pub path: String,
     pub is_dir: bool,
     pub size: Option<u64>,
+    /// CHD tracks only: CD track type from the track metadata, e.g. `MODE2_RAW`, `AUDIO`.
+    /// `path` is then a synthetic `trackNN.bin` name and `size` its uncompressed length.
+    pub track_type: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -946,6 +2583,26 @@ pub mod library {
 
     /// Scan an arbitrary directory for games (preview scan). Intended for UI import flows.
     ///
+    /// `.cue`/`.gdi`/`.ccd` files become the primary entry and their referenced tracks are
+    /// attached as `companion_files` (not listed separately). References must be relative and
+    /// stay inside the sheet's directory; absolute or `..` references are rejected with a warning.
+    ///
+    /// Files are hashed in parallel (`ScanSettings.hash_concurrency`); results keep walk order.
+    ///
+    /// `.chd` files are read natively: `format` is `chd`, hashes cover the decompressed data
+    /// stream (so `sha1` matches the CHD's raw data SHA1), and `logical_size` is reported.
+    ///
+    /// For PS1/PS2 `.iso`/`.bin`/`.chd`, the ISO9660 root is read to parse `SYSTEM.CNF` for
+    /// the boot serial (CHD: only the needed hunks are decompressed). Malformed images leave
+    /// `disc_serial` empty without failing the scan.
+    ///
+    /// Platform comes from header magic (`definitions::get_header_signatures`) when a
+    /// signature matches, overriding the extension; otherwise from the extension mapping.
+    ///
+    /// Single-ROM `.zip`/`.7z` archives are hashed by streaming the decompressed inner file
+    /// (see `ScannedGame.archive_entry`), so CRC32s match No-Intro/Redump DATs. Archives with
+    /// more than one ROM are hashed as containers and carry a warning.
+    ///
     /// NOTE: For full-library scans, prefer `rescan_library()` which is event-driven.
     #[tauri::command]
     pub async fn scan_directory(path: String, recursive: bool) -> CommandResult<Vec<ScannedGame>> {
@@ -956,20 +2613,91 @@ pub mod library {
     ///
     /// This command should return quickly with a `ScanResult` in `Started` state.
     /// Progress + completion should be emitted via `scan_progress` / `scan_complete`.
+    ///
+    /// Files named `... (Disc N)`, `(Disk N)` or `(CD N)` that share the rest of their name,
+    /// directory and platform are merged into one game with `disc_files`; an existing `.m3u`
+    /// listing them becomes its `m3u_path`.
+    ///
+    /// Processed files are checkpointed periodically. With `resume = true` and a valid
+    /// checkpoint, the scan keeps the checkpoint's `scan_id` and skips files already
+    /// processed; otherwise any checkpoint is discarded and a fresh scan starts.
+    #[tauri::command]
+    pub async fn rescan_library(resume: bool) -> CommandResult<ScanResult> {
+        todo!()
+    }
+
+    /// Past scan results, newest first. Errors/warnings are included in full.
+    #[tauri::command]
+    pub async fn get_scan_history(limit: Option<u32>) -> CommandResult<Vec<ScanResult>> {
+        todo!()
+    }
+
+    /// A single persisted scan result by id.
     #[tauri::command]
-    pub async fn rescan_library() -> CommandResult<ScanResult> {
+    pub async fn get_scan_result(scan_id: String) -> CommandResult<Option<ScanResult>> {
+        todo!()
+    }
+
+    /// Resumable checkpoint left by an interrupted scan, if any.
+    ///
+    /// Returns `None` (and drops the checkpoint) when library roots or scan settings changed
+    /// since it was written.
+    #[tauri::command]
+    pub async fn get_scan_checkpoint() -> CommandResult<Option<ScanCheckpoint>> {
+        todo!()
+    }
+
+    /// List existing files under `path` that `globs` would exclude from a scan.
+    ///
+    /// `globs = None` previews the configured `ScanSettings.exclude_globs`. Nothing is scanned
+    /// or hashed. Pruned directories are listed once, with a trailing `/`.
+    #[tauri::command]
+    pub async fn preview_scan_exclusions(path: String, globs: Option<Vec<String>>) -> CommandResult<Vec<ExcludedPath>> {
         todo!()
     }
 
     /// Cancel the currently-running scan (if any).
+    ///
+    /// Queued files are dropped immediately; files already being hashed stop at their next
+    /// read chunk.
+    #[tauri::command]
+    pub async fn cancel_scan() -> CommandResult<CancelOutcome> {
+        todo!()
+    }
+
+    // Watching ----------------------------------------------------------------
+
+    /// Start watching `AppSettings.library_roots` for changes; emits `library_changed`.
+    ///
+    /// Missing roots are polled every 30s and attached once they reappear, so an unplugged
+    /// drive does not stop the watcher. Changing `library_roots` while watching re-targets
+    /// the watcher. Calling this while already watching is a no-op. Returns the roots
+    /// being watched now.
+    #[tauri::command]
+    pub async fn start_library_watch() -> CommandResult<Vec<String>> {
+        todo!()
+    }
+
+    /// Stop the library watcher. Buffered events are dropped. Returns false if it was not
+    /// running.
     #[tauri::command]
-    pub async fn cancel_scan() -> CommandResult<bool> {
+    pub async fn stop_library_watch() -> CommandResult<bool> {
         todo!()
     }
 
     /// List games with filtering + pagination.
+    ///
+    /// Offset mode (`page`) is used unless `pagination.cursor` is set. Cursor mode seeks with
+    /// `WHERE (sort_key, id) > (...)`, so deep pages cost the same as the first; `page` is
+    /// echoed back as 0 and `total_items`/`total_pages` are still filled.
+    ///
+    /// `include_facets` additionally runs the per-platform/per-region aggregation.
     #[tauri::command]
-    pub async fn get_games(filter: GameFilter, pagination: Pagination) -> CommandResult<PaginatedGames> {
+    pub async fn get_games(
+        filter: GameFilter,
+        pagination: Pagination,
+        include_facets: bool,
+    ) -> CommandResult<PaginatedGames> {
         todo!()
     }
 
@@ -979,15 +2707,136 @@ pub mod library {
         todo!()
     }
 
+    /// Record a launch: increments `play_count` and sets `last_played` to now.
+    #[tauri::command]
+    pub async fn record_play(game_id: i64) -> CommandResult<Game> {
+        todo!()
+    }
+
     /// Update a game record.
     #[tauri::command]
     pub async fn update_game(id: i64, updates: GameUpdate) -> CommandResult<Game> {
         todo!()
     }
 
+    /// Apply the same partial update to many games in one transaction.
+    ///
+    /// `None` fields are left untouched on every row. `metadata` is merged into each row
+    /// (`MergeShallow`) unless `metadata_strategy` says otherwise. `user_tags` replaces user
+    /// tags per row, as in `update_game`.
+    #[tauri::command]
+    pub async fn update_games(ids: Vec<i64>, updates: GameUpdate, all_or_nothing: bool) -> CommandResult<GameBatchResult> {
+        todo!()
+    }
+
     /// Batch delete games by id.
+    ///
+    /// With `soft`, rows are only stamped with `deleted_at` (moved to the trash) and can be
+    /// brought back with `restore_games`; `soft` together with `delete_files` is rejected.
+    ///
+    /// With `delete_files`, each game's files (`filepath`, `companion_files`, `disc_files`,
+    /// `m3u_path`) are removed from disk first and the row only afterwards; a failed file
+    /// delete keeps the row and lands in `failures`. Games with any file outside
+    /// `library_roots` are refused and reported in `skipped_ids`.
+    #[tauri::command]
+    pub async fn delete_games(
+        ids: Vec<i64>,
+        all_or_nothing: bool,
+        delete_files: bool,
+        soft: bool,
+    ) -> CommandResult<DeleteResult> {
+        todo!()
+    }
+
+    /// Take games out of the trash. Returns the number restored; ids not in the trash are
+    /// ignored.
+    #[tauri::command]
+    pub async fn restore_games(ids: Vec<i64>) -> CommandResult<u64> {
+        todo!()
+    }
+
+    /// Permanently delete trashed rows (DB only), optionally just those trashed more than
+    /// `older_than_days` ago. Returns the number purged.
+    #[tauri::command]
+    pub async fn purge_trash(older_than_days: Option<u32>) -> CommandResult<u64> {
+        todo!()
+    }
+
+    /// Drop and repopulate the title search index from `games`. Returns rows indexed.
+    ///
+    /// Only needed if the index drifted (e.g. rows edited outside the app).
+    #[tauri::command]
+    pub async fn rebuild_search_index() -> CommandResult<u64> {
+        todo!()
+    }
+
+    /// One-time backfill: re-parse filenames of existing games to fill `regions`/`languages`/
+    /// `revision`.
+    ///
+    /// Raw metadata is left untouched. Safe to re-run.
+    #[tauri::command]
+    pub async fn backfill_regions() -> CommandResult<BackfillResult> {
+        todo!()
+    }
+
+    /// Check that every game's files still exist (and, with `check_sizes`, still have the
+    /// recorded size). Trashed games are skipped.
+    ///
+    /// Emits `scan_progress`/`scan_complete` with `ScanKind::Integrity`. Updates
+    /// `games.is_missing` and stores the result that `get_issues` reports.
+    #[tauri::command]
+    pub async fn verify_library_integrity(check_sizes: bool) -> CommandResult<IntegrityReport> {
+        todo!()
+    }
+
+    /// Point a game at its moved file, keeping id, metadata, tags and collection memberships.
+    ///
+    /// `new_path` must exist and not belong to another game. The file is rehashed, and if
+    /// the game had stored hashes that differ, the relink is refused with `InvalidInput`.
+    /// Clears `is_missing`.
+    #[tauri::command]
+    pub async fn relink_game(game_id: i64, new_path: String) -> CommandResult<Game> {
+        todo!()
+    }
+
+    /// Match games against a No-Intro/Redump XML DAT by SHA1, MD5 or CRC32 (strongest first).
+    ///
+    /// Every requested id gets an entry; unmatched games have `verified: false`. Results are
+    /// stored on the game (`verified`, `dat_name`). When the DAT header names a skipper
+    /// (e.g. `No-Intro_NES.xml` for iNES), the file is rehashed without that header before
+    /// matching, since stored hashes cover the whole file.
+    #[tauri::command]
+    pub async fn match_against_dat(game_ids: Vec<i64>, dat_path: String) -> CommandResult<HashMap<i64, DatMatch>> {
+        todo!()
+    }
+
+    /// Group non-trashed games with identical content.
+    ///
+    /// Games are compared on `algorithm`; a pair where either side lacks it falls back to
+    /// the strongest hash both have (sha1 > md5 > crc32). Results are stored for the
+    /// `Duplicate` category of `get_issues`.
+    #[tauri::command]
+    pub async fn find_duplicates(algorithm: HashAlgorithm) -> CommandResult<Vec<DuplicateGroup>> {
+        todo!()
+    }
+
+    /// Keep one game of a duplicate set and delete the others.
+    ///
+    /// Collection memberships and user tags of removed games move to `keep` first. Deletion
+    /// follows `delete_games` (same library-root guard when `delete_files` is set). Every id
+    /// in `remove` must share a hash with `keep`, otherwise `InvalidInput`.
+    #[tauri::command]
+    pub async fn resolve_duplicates(keep: i64, remove: Vec<i64>, delete_files: bool) -> CommandResult<DeleteResult> {
+        todo!()
+    }
+
+    /// Aggregate "needs attention" view over the library, scoped by `filter`.
+    ///
+    /// Cheap categories (missing file, missing hashes, unknown platform, no metadata) are
+    /// computed via SQL. Duplicate and integrity categories reuse the last stored results
+    /// and set `needs_refresh` instead of rehashing.
     #[tauri::command]
-    pub async fn delete_games(ids: Vec<i64>) -> CommandResult<DeleteResult> {
+    pub async fn get_issues(filter: GameFilter) -> CommandResult<LibraryIssuesReport> {
         todo!()
     }
 
@@ -1005,6 +2854,18 @@ pub mod library {
         todo!()
     }
 
+    /// Create a smart collection whose members are whatever `filter` matches at query time.
+    ///
+    /// Stored as `collection_type = 'auto'` with the filter in `rules_json`. Resolved
+    /// membership is cached and dropped on any library change (scan, update, delete).
+    /// Using its id as `GameFilter.collection_id` runs the stored filter; a filter that
+    /// refers back to its own collection (directly or through others) is rejected.
+    /// Adding/removing members explicitly is rejected with `InvalidInput`.
+    #[tauri::command]
+    pub async fn create_smart_collection(name: String, filter: GameFilter) -> CommandResult<Collection> {
+        todo!()
+    }
+
     /// Update a collection.
     #[tauri::command]
     pub async fn update_collection(id: i64, updates: CollectionUpdate) -> CommandResult<Collection> {
@@ -1017,15 +2878,52 @@ pub mod library {
         todo!()
     }
 
-    /// Add games to a collection (batch).
+    /// Add games to a collection (batch). New members are appended after the current last
+    /// position; games already present keep theirs.
     #[tauri::command]
-    pub async fn add_games_to_collection(collection_id: i64, game_ids: Vec<i64>) -> CommandResult<Collection> {
+    pub async fn add_games_to_collection(
+        collection_id: i64,
+        game_ids: Vec<i64>,
+        all_or_nothing: bool,
+    ) -> CommandResult<CollectionBatchResult> {
         todo!()
     }
 
     /// Remove games from a collection (batch).
     #[tauri::command]
-    pub async fn remove_games_from_collection(collection_id: i64, game_ids: Vec<i64>) -> CommandResult<Collection> {
+    pub async fn remove_games_from_collection(
+        collection_id: i64,
+        game_ids: Vec<i64>,
+        all_or_nothing: bool,
+    ) -> CommandResult<CollectionBatchResult> {
+        todo!()
+    }
+
+    /// Set the full manual order of a collection (`collection_games.sort_order`).
+    ///
+    /// `ordered_game_ids` must contain every current member exactly once, otherwise
+    /// `InvalidInput`. Rejected for smart collections.
+    #[tauri::command]
+    pub async fn reorder_collection(collection_id: i64, ordered_game_ids: Vec<i64>) -> CommandResult<Collection> {
+        todo!()
+    }
+
+    /// Export a collection as a frontend playlist, in the collection's stored order.
+    ///
+    /// With `device_profile_id`, entries use the resolved on-device ROM paths; otherwise
+    /// local library paths. Games that can't be rendered are skipped with a warning.
+    ///
+    /// `.lpl` entries carry `path`, `label` (display title), `crc32` (`<hash>|crc` from the
+    /// stored hash, `DETECT` if none), `db_name` (the platform's RetroArch playlist name) and
+    /// `core_path`/`core_name` from the platform's core override, else `DETECT`. ROMs inside
+    /// single-ROM archives use RetroArch's `archive.zip#inner.rom` syntax.
+    #[tauri::command]
+    pub async fn export_collection(
+        collection_id: i64,
+        format: PlaylistFormat,
+        path: String,
+        device_profile_id: Option<i64>,
+    ) -> CommandResult<PlaylistExportResult> {
         todo!()
     }
 }
@@ -1037,27 +2935,50 @@ pub mod device {
     use super::*;
 
     /// Scan for currently connected storage devices / mount points.
+    ///
+    /// Each device is matched against stored destination fingerprints (see `DeviceMatch`)
+    /// so saved overrides keep applying when the device path or label changes.
+    ///
+    /// Android devices visible to `adb devices` (state `device`) are listed too, with
+    /// `DeviceTransport::Adb`. A missing `adb` only skips them; it is not an error here.
     #[tauri::command]
     pub async fn scan_connected_devices() -> CommandResult<Vec<DetectedDevice>> {
         todo!()
     }
 
     /// Scan a destination path (e.g. SD card root) for markers, known folder structure, etc.
+    ///
+    /// `path` may be an `sftp://` URL; see `DeploymentConfig.destination_path`.
     #[tauri::command]
     pub async fn scan_destination(path: String) -> CommandResult<DestinationScanResult> {
         todo!()
     }
 
     /// Detect OS layout from a destination (heuristics/markers).
+    ///
+    /// Markers and paths are matched against the pack's templates (see
+    /// `definitions::get_os_layout`).
     #[tauri::command]
     pub async fn detect_os_layout(path: String) -> CommandResult<LayoutDetectionResult> {
         todo!()
     }
 
+    /// Detect which emulators/cores are installed on a destination.
+    ///
+    /// Checks the install locations and markers the definition pack lists for `os_id`
+    /// (core files, app folders, binaries). Only emulators with evidence are returned.
+    #[tauri::command]
+    pub async fn detect_installed_emulators(path: String, os_id: String) -> CommandResult<InstalledEmulatorsResult> {
+        todo!()
+    }
+
     /// Resolve final deployment paths using:
-    /// - expected profile paths
+    /// - expected profile paths (the OS's `LayoutTemplate`)
     /// - detected layout paths
     /// - user overrides (if enabled)
+    ///
+    /// For `adb://` destinations, known Android layouts under `/storage/emulated/0` are
+    /// recognized (RetroArch `RetroArch/`, `ROMs/`, Daijisho/ES-DE `ES-DE/`, ...).
     #[tauri::command]
     pub async fn resolve_deployment_paths(
         destination_path: String,
@@ -1067,6 +2988,13 @@ pub mod device {
         todo!()
     }
 
+    /// Read `.romrunner-manifest.json` from a destination root. `Ok(None)` when there is
+    /// none; an unreadable or unknown-version manifest is `InvalidInput`.
+    #[tauri::command]
+    pub async fn read_deployment_manifest(path: String) -> CommandResult<Option<DeploymentManifest>> {
+        todo!()
+    }
+
     // Management --------------------------------------------------------------
 
     /// List user-saved devices.
@@ -1129,40 +3057,219 @@ pub mod deploy {
     /// Build a deployment plan from a config.
     ///
     /// The plan should include all copy operations (ROMs, BIOS, saves/states, metadata).
+    /// Creation is idempotent: a cached plan with the same `config_hash` is rebuilt and its
+    /// `plan_id` is kept only if the rebuilt `content_hash` still matches.
+    ///
+    /// Companion track files are emitted as items sharing the game's `game_id`.
+    ///
+    /// With `include_media`, each game's cover and screenshot are looked up first via paths
+    /// stored in `metadata` (`media.cover`, `media.screenshot`), then as
+    /// `<AppSettings.media_dir>/<platform>/covers|screenshots/<rom stem>.png|jpg`. Found
+    /// files become `Media` items placed per `media_layout` (RetroArch thumbnails are
+    /// converted to PNG). Games with no cover are listed in one plan warning.
+    ///
+    /// With `generate_gamelist`, one `Metadata` item per platform writes `gamelist.xml`:
+    /// next to the platform's ROMs, or `ES-DE/gamelists/<platform>/` when the profile's
+    /// frontend is `emulationstation-de`. `source_path` is empty (the file is generated)
+    /// and `bytes` is an estimate. Each game gets a `<game>` with `path` (`./<file>`,
+    /// relative to the ROM folder) and `name`; `desc`, `genre`, `releasedate`, `developer`,
+    /// `publisher`, `players` and `rating` (0-1, from `Game.rating` else metadata) come
+    /// from `metadata` when present, and `image`/`thumbnail` only when media is deployed.
+    /// Entries an existing gamelist has for files outside the plan are kept.
+    ///
+    /// Non-`Copy` `link_mode`s are checked here: a destination on another filesystem (or
+    /// one that can't hold the link type) yields a plan warning and plain copy items.
+    /// Reflink support is only known once tried, so `start_deployment` may still fall back
+    /// per file, reporting it in `DeploymentComplete.warnings`.
+    ///
+    /// Existing destination files are resolved per `conflict_strategy` and the result is
+    /// recorded in `DeploymentItem.conflict`.
+    ///
+    /// Unless `force_recopy`, each item is compared with the file already at `dest_path`:
+    /// size first, then hash (source from the library, destination through the hash cache)
+    /// only when sizes match. Identical files get `skip_reason = Identical`. Skipped items
+    /// are part of `content_hash`, so a later change at the destination yields a new plan.
+    /// A destination file listed in the manifest (see `device::read_deployment_manifest`)
+    /// with the same size and an mtime no later than `deployed_at` reuses the manifest hash
+    /// instead of being re-read.
+    ///
+    /// With `SyncMode::Mirror`, orphaned files under the resolved `roms` path are listed as
+    /// `Delete` items. Planning never deletes anything, so the plan doubles as a dry run;
+    /// deletions only happen in `start_deployment`, after all copies succeeded.
+    ///
+    /// With `normalize_extensions`, renamed items record `renamed_from`, and `.cue`/`.gdi`
+    /// sheets referencing a renamed track are deployed with their references rewritten.
+    ///
+    /// The profile's `NameRule` (see `definitions::get_name_rules`) is applied the same way,
+    /// also setting `renamed_from`. Two items that end up with the same `dest_path` produce a
+    /// plan warning naming both games; the second keeps its untransformed name.
+    ///
+    /// With `sanitize_names`, names the destination filesystem would reject are replaced by
+    /// the same suggestion `validate_deployment_plan` reports (illegal characters become
+    /// `_`, long names are truncated before the extension). If that collides with another
+    /// item, ` (2)`, ` (3)`, ... is appended to the stem and a plan warning is added.
+    #[tauri::command]
+    pub async fn create_deployment_plan(config: DeploymentConfig) -> CommandResult<DeploymentPlan> {
+        todo!()
+    }
+
+    /// Validate an existing plan (paths still exist, destination writable, enough space, etc).
+    ///
+    /// Writability is checked for real: a tiny temp file is created and deleted at the
+    /// resolved ROM path (always cleaned up, even on failure) instead of trusting mount
+    /// metadata. A failed probe makes the plan invalid and is reported in `write_test`.
+    ///
+    /// A game whose companion track files are missing from the source is an error.
+    ///
+    /// When the destination filesystem (from `DetectedDevice.filesystem`) is FAT32, every
+    /// unsplit item over 4 GiB is an error naming the game and its size. exFAT destinations
+    /// get a warning if the device's OS is known to only read FAT32, and FAT32 destinations
+    /// with large disc images get a note that exFAT/NTFS lift the limit.
+    ///
+    /// Each `dest_path` is also checked against the filesystem's naming rules; problems are
+    /// returned in `name_issues` with a suggested name. They are warnings, not errors.
+    #[tauri::command]
+    pub async fn validate_deployment_plan(plan: DeploymentPlan) -> CommandResult<ValidationResult> {
+        todo!()
+    }
+
+    /// Classify every plan item against the destination without writing anything.
+    ///
+    /// Uses the same size-then-hash comparison as plan creation, but re-checks the
+    /// destination now (so it also reflects changes since planning, and ignores
+    /// `force_recopy`). With `operation_id`, `cancel_preview` stops it with `Cancelled`.
+    #[tauri::command]
+    pub async fn preview_deployment(plan: DeploymentPlan, operation_id: Option<String>) -> CommandResult<DeploymentDiff> {
+        todo!()
+    }
+
+    /// Stop an in-flight `preview_deployment` started with `operation_id`.
+    #[tauri::command]
+    pub async fn cancel_preview(operation_id: String) -> CommandResult<CancelOutcome> {
+        todo!()
+    }
+
+    /// Start deploying a plan.
+    ///
+    /// Every created file is recorded; files about to be overwritten or mirror-deleted are
+    /// first moved to `.romrunner/rollback/<handle_id>/` on the destination (a rename, no
+    /// extra copy) so `rollback_deployment` can restore them.
+    ///
+    /// The plan and per-item completion are journaled to the DB as the run progresses, so
+    /// an interrupted run can continue via `resume_deployment_by_id`.
+    ///
+    /// Progress + completion must be emitted via `deployment_progress` / `deployment_complete`.
+    ///
+    /// After the last item, `.romrunner-manifest.json` is written to the destination root
+    /// (temp file + rename), merging the previous manifest with this run: copied items are
+    /// added or replaced, mirror deletes are dropped. A cancelled or failed run still writes
+    /// the items it finished.
+    ///
+    /// `adb://` destinations push with `adb push`, parsing its percentage output into
+    /// progress; a missing `adb` fails with `ToolNotFound`.
+    ///
+    /// `sftp://` destinations go through the same backend as local ones: progress is
+    /// reported per written chunk, free space comes from `statvfs@openssh.com` when
+    /// available, and `verify_after_copy` hashes remotely with `sha1sum`/`md5sum` over
+    /// SSH when present, else by reading the file back.
+    ///
+    /// Items with `convert_to = chd` are compressed into `AppSettings.temp_dir` first
+    /// (`DeploymentStage::Compressing`), then copied. Temp output is removed on completion,
+    /// failure and cancel.
+    ///
+    /// Destination free space is re-checked between files, at most every 2s or 256 MiB
+    /// written. If it drops below the remaining plan bytes, the run stops before the next
+    /// write and completes with a `DiskFull` error; files already copied are kept.
+    #[tauri::command]
+    pub async fn start_deployment(plan: DeploymentPlan) -> CommandResult<DeploymentHandle> {
+        todo!()
+    }
+
+    /// Add a plan to the deployment queue; the returned handle is the one its events and
+    /// `cancel_deployment` use.
+    ///
+    /// Entries start in FIFO order, up to `AppSettings.queue_concurrency` at a time, but
+    /// never two on the same physical device (matched by destination fingerprint, so two
+    /// profiles on one SD card serialize). Each entry behaves exactly like
+    /// `start_deployment`, emitting its own `deployment_complete`; a failed entry does not
+    /// stop the queue. `deployment_queue_drained` follows the last one.
+    ///
+    /// The queue is in memory: pending entries are dropped on restart, while a running
+    /// one is journaled and can be resumed as usual.
+    #[tauri::command]
+    pub async fn enqueue_deployment(plan: DeploymentPlan) -> CommandResult<DeploymentHandle> {
+        todo!()
+    }
+
+    /// Queue entries for this session: pending (in order), running, then finished ones.
+    #[tauri::command]
+    pub async fn get_queue() -> CommandResult<Vec<QueueEntry>> {
+        todo!()
+    }
+
+    /// Pause a running deployment. In-flight chunks finish, then every worker waits.
+    #[tauri::command]
+    pub async fn pause_deployment(handle: DeploymentHandle) -> CommandResult<bool> {
+        todo!()
+    }
+
+    /// Resume a paused deployment.
     #[tauri::command]
-    pub async fn create_deployment_plan(config: DeploymentConfig) -> CommandResult<DeploymentPlan> {
+    pub async fn resume_deployment(handle: DeploymentHandle) -> CommandResult<bool> {
         todo!()
     }
 
-    /// Validate an existing plan (paths still exist, destination writable, enough space, etc).
+    /// Restart an interrupted deployment from its journal, keeping the same handle id.
+    ///
+    /// Items journaled as `copied`/`verified` are skipped after a size check; the item that
+    /// was in flight is re-hashed at the destination and re-copied unless it already
+    /// matches. Progress resumes from the skipped count. The destination must be connected
+    /// (`DeviceNotConnected` otherwise).
     #[tauri::command]
-    pub async fn validate_deployment_plan(plan: DeploymentPlan) -> CommandResult<ValidationResult> {
+    pub async fn resume_deployment_by_id(handle_id: String) -> CommandResult<DeploymentHandle> {
         todo!()
     }
 
-    /// Start deploying a plan.
+    /// Cancel a running deployment.
     ///
-    /// Progress + completion must be emitted via `deployment_progress` / `deployment_complete`.
+    /// Unknown handles map to `NothingRunning`; finished ones to `AlreadyFinished`.
     #[tauri::command]
-    pub async fn start_deployment(plan: DeploymentPlan) -> CommandResult<DeploymentHandle> {
+    pub async fn cancel_deployment(handle: DeploymentHandle) -> CommandResult<CancelOutcome> {
         todo!()
     }
 
-    /// Pause a running deployment.
+    /// Copy saves and states from a device back into `AppSettings.local_saves_dir`.
+    ///
+    /// Scans the resolved `saves`/`states` paths and matches files to library games by
+    /// stem (after the profile's name rules), then by hash. `game_ids` limits the pull.
+    /// The destination manifest is the sync baseline: a file changed only on the device is
+    /// copied in, one changed only locally is left alone, and one changed on both sides is
+    /// reported in `DeploymentComplete.save_conflicts` without touching either copy.
+    /// Without a manifest entry, the newer file wins unless both exist with different
+    /// content, which is a conflict. Pulled files are recorded in the manifest.
+    ///
+    /// Runs like a deployment: same events, and `cancel_deployment` stops it.
     #[tauri::command]
-    pub async fn pause_deployment(handle: DeploymentHandle) -> CommandResult<bool> {
+    pub async fn pull_saves(
+        device_profile_id: i64,
+        destination_path: String,
+        game_ids: Option<Vec<i64>>,
+    ) -> CommandResult<DeploymentHandle> {
         todo!()
     }
 
-    /// Resume a paused deployment.
+    /// Undo a finished (or failed/cancelled) deployment: delete the files it created and
+    /// restore the ones it overwrote or deleted. Not available for a running deployment or
+    /// after the rollback window. Files changed on the device since are still reverted.
     #[tauri::command]
-    pub async fn resume_deployment(handle: DeploymentHandle) -> CommandResult<bool> {
+    pub async fn rollback_deployment(handle_id: String) -> CommandResult<RollbackResult> {
         todo!()
     }
 
-    /// Cancel a running deployment.
+    /// Accept a deployment: drop its backups and rollback record now instead of waiting
+    /// for the window to expire.
     #[tauri::command]
-    pub async fn cancel_deployment(handle: DeploymentHandle) -> CommandResult<bool> {
+    pub async fn confirm_deployment(handle_id: String) -> CommandResult<bool> {
         todo!()
     }
 
@@ -1171,6 +3278,18 @@ pub mod deploy {
     pub async fn get_deployment_history(device_id: Option<i64>) -> CommandResult<Vec<DeploymentRecord>> {
         todo!()
     }
+
+    /// Write a collection as a playlist for a locally installed frontend, using library paths.
+    ///
+    /// Same writer and entry rules as `library::export_collection` without a device profile.
+    #[tauri::command]
+    pub async fn export_collection_playlist(
+        collection_id: i64,
+        dest_path: String,
+        format: PlaylistFormat,
+    ) -> CommandResult<PlaylistExportResult> {
+        todo!()
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -1186,6 +3305,10 @@ pub mod bios {
     }
 
     /// Verify a BIOS file by hashing + matching against known database entries.
+    ///
+    /// `.zip`/`.7z` archives are verified without extracting: each entry is stream-hashed
+    /// through the same reader as `fs::list_archive_contents` and reported in `entries`.
+    /// `path` may also name a single entry as `archive.zip#entry`.
     #[tauri::command]
     pub async fn verify_bios_file(path: String) -> CommandResult<BiosVerificationResult> {
         todo!()
@@ -1194,8 +3317,38 @@ pub mod bios {
     /// Verify all BIOS files for a directory against the BIOS database.
     ///
     /// For large directories, implementations should emit scan events (kind=bios) and return quickly.
+    ///
+    /// Recurses up to `max_depth` levels below `directory` (`None` = 3; `0` = top level
+    /// only), so nested sets like `dc/` or `pcsx2/bios/` are found without wandering into
+    /// ROM folders. Symlinked directories are not followed. Files are hashed in parallel
+    /// under `AppSettings.io_read_budget`, with `total` known after the directory walk.
+    ///
+    /// A file's subpath is compared with each candidate's `bios_subdirectory` and
+    /// `alternate_subdirectories`: when several entries match by name or hash, the one
+    /// whose subdirectory matches wins, and `in_expected_subdirectory` is reported.
+    ///
+    /// Archives found on the walk are opened and each entry counts as its own scan unit
+    /// (in `total` and in `results`, with an `archive#entry` path). An entry's directory
+    /// inside the archive is used for the subdirectory check.
+    #[tauri::command]
+    pub async fn verify_all_bios(directory: String, max_depth: Option<u32>) -> CommandResult<BiosVerificationReport> {
+        todo!()
+    }
+
+    /// Find correctly-hashed BIOS files under a non-canonical name in `directory` (same
+    /// depth rules as `verify_all_bios`). Alternate filenames the entry lists are not
+    /// reported, and no suggestion is made when the canonical name is already taken by a
+    /// matching file.
+    #[tauri::command]
+    pub async fn suggest_bios_renames(directory: String) -> CommandResult<Vec<BiosRenameSuggestion>> {
+        todo!()
+    }
+
+    /// Rename files as suggested. Each source is re-hashed first; a changed source or an
+    /// existing `to_path` is a failure, never an overwrite. Case-only renames go through a
+    /// temporary name so they also work on case-insensitive filesystems.
     #[tauri::command]
-    pub async fn verify_all_bios(directory: String) -> CommandResult<BiosVerificationReport> {
+    pub async fn apply_bios_renames(renames: Vec<BiosRenameSuggestion>) -> CommandResult<BiosRenameResult> {
         todo!()
     }
 
@@ -1206,16 +3359,49 @@ pub mod bios {
     }
 
     /// Get BIOS requirements inferred for a device profile (OS + emulator defaults).
+    ///
+    /// Per platform the profile's OS supports, the emulator is the `PlatformOverride` if
+    /// set, else `definitions::get_default_emulator` for the OS. Its BIOS are the entries
+    /// listing it in `required_for_emulators` (-> `required`) or `optional_for_emulators`
+    /// (-> `optional`); required entries with `hle_fallback` are listed as optional.
+    /// A file needed by several platforms (same BIOS id) appears once, under the first
+    /// platform by id, with every emulator that needs it in `emulator_ids`. Platforms
+    /// without a resolvable emulator fall back to `get_bios_requirements` for that
+    /// platform, with `emulator_id = None`.
     #[tauri::command]
     pub async fn get_bios_requirements_for_device(device_profile_id: i64) -> CommandResult<Vec<BiosRequirement>> {
         todo!()
     }
 
+    /// Download BIOS entries missing from `directory`.
+    ///
+    /// For each id not already present, the URL comes from `AppSettings.bios_source_urls`,
+    /// else the preferred `known_hashes` entry's `source_url`; entries with neither are
+    /// skipped with a warning. Each download goes to a temp file and is hashed; only a
+    /// match against `known_hashes` is moved into place under the canonical filename (and
+    /// `bios_subdirectory`). A mismatch is deleted and reported as an error, never
+    /// installed. Existing files are never overwritten.
+    ///
+    /// Runs like a deployment: progress via `deployment_progress` (one item per BIOS),
+    /// `deployment_complete` at the end, `cancel_deployment` to stop.
+    #[tauri::command]
+    pub async fn download_missing_bios(directory: String, bios_ids: Vec<String>) -> CommandResult<DeploymentHandle> {
+        todo!()
+    }
+
     /// Check BIOS completeness for a directory against a set of platforms.
+    ///
+    /// Required entries of one platform that differ only by `region` form a single slot.
+    /// A slot is satisfied by its `preferred_region` variant (`None` = first match in
+    /// `AppSettings.region_priority`), else by any present variant with a note; the
+    /// remaining variants move to `missing_optional`. With `hle_counts_as_present`, a
+    /// missing entry with `hle_fallback` is satisfied and listed in `satisfied_by_hle`.
     #[tauri::command]
     pub async fn check_bios_completeness(
         directory: String,
         platform_ids: Vec<String>,
+        preferred_region: Option<Region>,
+        hle_counts_as_present: bool,
     ) -> CommandResult<BiasCompletenessReport> {
         todo!()
     }
@@ -1230,12 +3416,18 @@ pub mod compat {
     /// Lookup performance record for a game + device.
     ///
     /// `device_id` is accepted here for UI ergonomics; implementation should resolve device->chipset.
+    ///
+    /// When several sources have a row, the one ranked highest by
+    /// `AppSettings.compat_source_priority` is returned with the rest in `alternatives`.
+    /// Batch lookups apply the same ranking but omit `alternatives`.
     #[tauri::command]
     pub async fn get_game_performance(game_id: String, device_id: String) -> CommandResult<Option<GamePerformance>> {
         todo!()
     }
 
     /// Lookup emulator compatibility status for a game + emulator.
+    ///
+    /// Multiple sources are ranked as in `get_game_performance`.
     #[tauri::command]
     pub async fn get_emulator_compatibility(game_id: String, emulator_id: String) -> CommandResult<Option<EmulatorCompat>> {
         todo!()
@@ -1256,13 +3448,127 @@ pub mod compat {
         todo!()
     }
 
+    /// Batch lookup emulator compatibility for multiple games.
+    ///
+    /// One pass over the in-memory cache; games without an entry are omitted from the map.
+    #[tauri::command]
+    pub async fn get_compat_batch(
+        game_ids: Vec<String>,
+        emulator_id: String,
+    ) -> CommandResult<HashMap<String, EmulatorCompat>> {
+        todo!()
+    }
+
+    /// Batch lookup recommended settings for multiple games.
+    ///
+    /// One pass over the in-memory cache; games without an entry are omitted from the map.
+    #[tauri::command]
+    pub async fn get_settings_batch(
+        game_ids: Vec<String>,
+        emulator_id: String,
+    ) -> CommandResult<HashMap<String, GameSettings>> {
+        todo!()
+    }
+
+    /// Build the launch command for a game on a device profile.
+    ///
+    /// The emulator comes from the usual resolution chain (game override -> platform override
+    /// -> OS profile default). RetroArch cores resolve to `retroarch -L {core_path} {rom_path}`;
+    /// standalone emulators use their pack-defined invocation. Errors with `InvalidInput`
+    /// when no emulator can be resolved for the game's platform.
+    #[tauri::command]
+    pub async fn get_launch_command(game_id: i64, device_profile_id: i64) -> CommandResult<LaunchCommand> {
+        todo!()
+    }
+
+    // User entries -------------------------------------------------------------
+
+    /// Record the user's own compatibility result for a game + emulator, replacing any
+    /// earlier user entry for that pair. Stored with `source = "user"` (other source
+    /// fields are ignored) and visible to lookups immediately.
+    #[tauri::command]
+    pub async fn set_user_compat(entry: EmulatorCompat) -> CommandResult<EmulatorCompat> {
+        todo!()
+    }
+
+    /// Record the user's own performance tier for a game on a chipset (and emulator, if
+    /// set), replacing any earlier user entry for that key.
+    #[tauri::command]
+    pub async fn set_user_performance(entry: GamePerformance) -> CommandResult<GamePerformance> {
+        todo!()
+    }
+
+    /// List all user-entered compatibility and performance rows.
+    #[tauri::command]
+    pub async fn get_user_compat_entries() -> CommandResult<UserCompatEntries> {
+        todo!()
+    }
+
+    /// Delete a user compat entry. Returns false if there was none.
+    #[tauri::command]
+    pub async fn delete_user_compat(game_id: String, emulator_id: String) -> CommandResult<bool> {
+        todo!()
+    }
+
+    /// Delete a user performance entry. Returns false if there was none.
+    #[tauri::command]
+    pub async fn delete_user_performance(
+        game_id: String,
+        chipset_id: String,
+        emulator_id: Option<String>,
+    ) -> CommandResult<bool> {
+        todo!()
+    }
+
+    /// Sources present in the loaded compatibility data, in effective priority order.
+    /// Reorder them through `AppSettings.compat_source_priority`.
+    #[tauri::command]
+    pub async fn list_compat_sources() -> CommandResult<Vec<CompatSource>> {
+        todo!()
+    }
+
+    /// Pick the best emulator for a game on a device.
+    ///
+    /// A game override's `force_emulator_id`, then a platform override, wins outright.
+    /// Otherwise every emulator supporting the platform and available on the device's OS is
+    /// scored as `weights . (performance, compat, os_default)` (defaults 0.6 / 0.3 / 0.1)
+    /// and the highest wins; ties go to the OS default, then emulator id. `Ok(None)` when
+    /// no emulator supports the game's platform.
+    #[tauri::command]
+    pub async fn recommend_emulator(game_id: String, device_id: String) -> CommandResult<Option<EmulatorRecommendation>> {
+        todo!()
+    }
+
+    /// Library game ids whose performance tier on the device's chipset is at least
+    /// `min_tier`, optionally limited to `platform_ids`, in library title order.
+    ///
+    /// Records with `exclude_from_auto_lists` are left out. A `GameOverride` with
+    /// `Include` always adds the game and `Exclude` always removes it. Games with no
+    /// performance record for the chipset are kept only with `include_unrated`. Trashed
+    /// games are never returned.
+    #[tauri::command]
+    pub async fn auto_list(
+        device_id: String,
+        min_tier: PerformanceTier,
+        platform_ids: Option<Vec<String>>,
+        include_unrated: bool,
+    ) -> CommandResult<Vec<i64>> {
+        todo!()
+    }
+
     /// Refresh in-memory caches for performance/compat/settings lookups.
+    ///
+    /// Built tables are persisted to `<app data>/cache/compat-<pack version>.bin`. On the
+    /// first lookup after launch (and here), a cache file for the loaded pack's version is
+    /// used when present and readable; otherwise the pack is parsed, the file rewritten,
+    /// and files for other versions removed. A pack without a version is never cached.
     #[tauri::command]
     pub async fn refresh_compatibility_cache() -> CommandResult<RefreshResult> {
         todo!()
     }
 
-    /// Clear in-memory caches (forces lazy reload).
+    /// Clear in-memory caches and the persisted cache file (forces a full rebuild on the
+    /// next lookup).
     #[tauri::command]
     pub async fn clear_compatibility_cache() -> CommandResult<bool> {
         todo!()
@@ -1282,8 +3588,117 @@ pub mod settings {
     }
 
     /// Update global app settings.
+    ///
+    /// Runs `validate_settings` first and rejects the update with `InvalidInput` naming the
+    /// first failing field (camelCase, e.g. `libraryRoots[1]`). With `force`, path checks
+    /// (existence, readability) are skipped so paths on unmounted drives can be saved;
+    /// value checks such as `ioReadBudget >= 1` still apply.
+    #[tauri::command]
+    pub async fn update_settings(updates: SettingsUpdate, force: bool) -> CommandResult<AppSettings> {
+        todo!()
+    }
+
+    /// Check an update without saving it.
+    ///
+    /// Library roots, `default_bios_dir`, `temp_dir`, `local_saves_dir` and `media_dir` must
+    /// be readable directories (`temp_dir` also writable); `definition_pack_path` and
+    /// `adb_path` readable files. Each error is `<field>: <reason>`. Duplicate or nested
+    /// library roots are warnings. `write_test` and `name_issues` are always empty here.
+    #[tauri::command]
+    pub async fn validate_settings(updates: SettingsUpdate) -> CommandResult<ValidationResult> {
+        todo!()
+    }
+
+    /// Most recent log entries (newest last), optionally filtered to `level` and above.
+    ///
+    /// Every command runs inside a tracing span and logs its `CommandError` on failure, so
+    /// failures are retrievable here. Secrets (auth tokens, passwords) are never logged.
+    #[tauri::command]
+    pub async fn get_recent_logs(lines: u32, level: Option<LogLevel>) -> CommandResult<Vec<LogEntry>> {
+        todo!()
+    }
+
+    /// Current vs target schema version and any pending migrations.
+    ///
+    /// Migrations run automatically at startup after snapshotting the DB next to it. A failed
+    /// migration is rolled back, the app keeps running read-only, and the failure is reported
+    /// here (and as `MigrationFailed` from commands that need the DB).
+    #[tauri::command]
+    pub async fn get_schema_status() -> CommandResult<SchemaStatus> {
+        todo!()
+    }
+
+    /// Snapshot the database to `path` using SQLite's online backup (safe while in use).
+    #[tauri::command]
+    pub async fn backup_database(path: String) -> CommandResult<DatabaseBackup> {
+        todo!()
+    }
+
+    // Presets -----------------------------------------------------------------
+
+    /// Save the current `AppSettings` (scan settings included) as a named preset,
+    /// replacing a preset of the same name, and mark it active.
+    #[tauri::command]
+    pub async fn save_preset(name: String) -> CommandResult<SettingsPreset> {
+        todo!()
+    }
+
+    /// List presets by name.
+    #[tauri::command]
+    pub async fn list_presets() -> CommandResult<Vec<SettingsPreset>> {
+        todo!()
+    }
+
+    /// Replace the current settings with a preset's and mark it active. Settings changes
+    /// take effect as with `update_settings`. Unknown names are `NotFound`.
+    #[tauri::command]
+    pub async fn apply_preset(name: String) -> CommandResult<AppSettings> {
+        todo!()
+    }
+
+    /// Name of the last saved or applied preset. Cleared by any later `update_settings`,
+    /// since the settings then no longer match it.
+    #[tauri::command]
+    pub async fn get_active_preset() -> CommandResult<Option<String>> {
+        todo!()
+    }
+
+    /// Delete a preset. Returns false if it did not exist.
+    #[tauri::command]
+    pub async fn delete_preset(name: String) -> CommandResult<bool> {
+        todo!()
+    }
+
+    /// Write a portable settings bundle (JSON) to `path`.
+    ///
+    /// The bundle has a `bundleVersion` (currently 1) and holds `AppSettings`, every
+    /// platform override, every user path override, and game overrides keyed by the
+    /// game's SHA1 (else MD5, else CRC32 + size) instead of its id, so they apply to
+    /// another library. Remote host secrets stay in the keychain and are never exported.
     #[tauri::command]
-    pub async fn update_settings(updates: SettingsUpdate) -> CommandResult<AppSettings> {
+    pub async fn export_settings(path: String) -> CommandResult<SettingsExport> {
+        todo!()
+    }
+
+    /// Apply a bundle written by `export_settings`.
+    ///
+    /// With `merge`, imported settings and overrides win per key and everything else is
+    /// kept; without it, settings are replaced and existing overrides deleted first. The
+    /// whole import runs in one transaction. Nothing is dropped silently: game overrides
+    /// with no local game of that hash are skipped, and paths that don't exist here
+    /// (library roots, BIOS/temp/media dirs) are still imported; both are listed in
+    /// `issues`. A bundle newer than this app supports fails with `InvalidInput`.
+    #[tauri::command]
+    pub async fn import_settings(path: String, merge: bool) -> CommandResult<SettingsImportResult> {
+        todo!()
+    }
+
+    /// Structured backend health report.
+    ///
+    /// Never fails because a subsystem is broken: each one is probed independently and
+    /// reported as ok/degraded/failed with a message.
+    #[tauri::command]
+    pub async fn get_diagnostics() -> CommandResult<Diagnostics> {
         todo!()
     }
 
@@ -1340,6 +3755,21 @@ pub mod settings {
     pub async fn delete_user_path_override(destination_id: String) -> CommandResult<bool> {
         todo!()
     }
+
+    // Remote hosts ------------------------------------------------------------
+
+    /// Store a password or key passphrase for a `RemoteHost` in the OS keychain.
+    #[tauri::command]
+    pub async fn set_remote_secret(host: String, username: String, secret: String) -> CommandResult<bool> {
+        todo!()
+    }
+
+    /// Trust the key a host presented (from a `HostKeyUnknown` error). Refuses to replace
+    /// an already-trusted, different fingerprint; remove the host first for that.
+    #[tauri::command]
+    pub async fn trust_host_key(host: String, fingerprint: String) -> CommandResult<RemoteHost> {
+        todo!()
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -1349,8 +3779,95 @@ pub mod definitions {
     use super::*;
 
     /// Load a definition pack (optional explicit path). If `None`, load bundled/default.
+    ///
+    /// The pack is validated first (see `validate_definition_pack`). Errors refuse the load
+    /// with `InvalidInput` (field `definitionPack`, message listing the first errors) and
+    /// keep the current pack, unless `force` is set; entities that fail to deserialize are
+    /// then skipped.
+    #[tauri::command]
+    pub async fn load_definition_pack(path: Option<String>, force: bool) -> CommandResult<DefinitionPackMeta> {
+        todo!()
+    }
+
+    /// Load an ordered list of packs as layers over each other; later layers win.
+    ///
+    /// Entities are merged by id within each entity type, field by field. Scalars and
+    /// arrays are replaced, except that a `+`-prefixed array key (e.g. `"+aliases"`,
+    /// `"+platforms"`) appends to the earlier value, de-duplicated. Entities only in a later
+    /// layer are added. Each layer is validated on its own and the merged result is
+    /// cross-referenced as a whole, so a personal pack may reference chipsets from the
+    /// official one. Every layer's `minAppVersion` must be satisfied.
+    ///
+    /// Subsequent `get_*`/`search_*` calls see the merged view and a watch covers every
+    /// layer. `force` behaves as in `load_definition_pack`. An empty `paths` is
+    /// `InvalidInput`.
+    #[tauri::command]
+    pub async fn load_definition_packs(paths: Vec<String>, force: bool) -> CommandResult<DefinitionPackMeta> {
+        todo!()
+    }
+
+    /// Watch the loaded pack's files and reload on change (debounced 500ms).
+    ///
+    /// A reload validates the whole pack first and swaps it in only without errors;
+    /// compat caches (memory and disk) are then cleared and `definitions_reloaded` is
+    /// emitted. On failure the old pack stays loaded and `definitions_reload_failed` is
+    /// emitted. With layered packs a change in any layer rebuilds the merged view.
+    /// Loading a different pack re-targets the watcher. Returns false if it was
+    /// already enabled.
+    #[tauri::command]
+    pub async fn enable_pack_watch() -> CommandResult<bool> {
+        todo!()
+    }
+
+    /// Stop watching the pack. Returns false if it was not enabled.
+    #[tauri::command]
+    pub async fn disable_pack_watch() -> CommandResult<bool> {
+        todo!()
+    }
+
+    /// Latest pack on `channel` (e.g. `stable`, `beta`) from the manifest at
+    /// `AppSettings.pack_update_url`. `None` when the base pack is already that version or
+    /// newer. The result is remembered for `download_pack_update`.
+    #[tauri::command]
+    pub async fn check_for_pack_update(channel: String) -> CommandResult<Option<PackUpdateInfo>> {
+        todo!()
+    }
+
+    /// Download and load the update found by the last `check_for_pack_update`.
+    ///
+    /// Emits `pack_download_progress`. The archive must match the manifest's `sha256`, and
+    /// the manifest's signature must verify against the key built into the app; a mismatch
+    /// fails with `InvalidInput` (field `packUpdate`) and nothing is stored. An incompatible
+    /// update, or no prior check, is `InvalidInput` too. The pack is unpacked to
+    /// `<app data>/packs/<version>/`, validated as in `load_definition_pack`, made the base
+    /// layer (user layers stay on top) and `definition_pack_path` is updated;
+    /// `definitions_reloaded` is emitted. The previous download is kept until the next one
+    /// succeeds.
+    #[tauri::command]
+    pub async fn download_pack_update() -> CommandResult<DefinitionPackMeta> {
+        todo!()
+    }
+
+    /// Structured diff of two packs (neither is loaded) over platforms, emulators, chipsets,
+    /// devices and operating systems, matched by id.
+    ///
+    /// Arrays of ids/strings compare as sets, reported as whole-array changes; other arrays
+    /// compare by index. When the schema versions differ only fields known to both are
+    /// compared (see `skipped_fields`). A pack that fails to parse is `InvalidInput`
+    /// naming `oldPath`/`newPath`.
+    #[tauri::command]
+    pub async fn diff_packs(old_path: String, new_path: String) -> CommandResult<PackDiff> {
+        todo!()
+    }
+
+    /// Check a pack without loading it.
+    ///
+    /// Checks structure and required fields against the JSON schema for the pack's declared
+    /// `schemaVersion` (unknown versions are an error), then cross-references: every
+    /// `Emulator.platforms` id, `DeviceCatalog.chipset_id` and `supported_os` id must exist,
+    /// and ids must be unique per entity type. Does not stop at the first problem.
     #[tauri::command]
-    pub async fn load_definition_pack(path: Option<String>) -> CommandResult<DefinitionPackMeta> {
+    pub async fn validate_definition_pack(path: String) -> CommandResult<PackValidationReport> {
         todo!()
     }
 
@@ -1390,24 +3907,48 @@ pub mod definitions {
         todo!()
     }
 
+    /// Expected layout of an OS (paths, markers, per-platform ROM folders) as defined by the
+    /// loaded pack; `None` if the pack has no template for it.
+    #[tauri::command]
+    pub async fn get_os_layout(os_id: String) -> CommandResult<Option<LayoutTemplate>> {
+        todo!()
+    }
+
     /// List frontends.
     #[tauri::command]
     pub async fn get_frontends() -> CommandResult<Vec<Frontend>> {
         todo!()
     }
 
-    /// Search platforms by query (name/alias).
+    /// Search platforms by query (name/alias), best match first.
+    ///
+    /// Matching is case- and punctuation-insensitive over `id`, `name` and `aliases`. Tiers,
+    /// in order: exact alias/id (so `gba` hits Game Boy Advance first), exact name, prefix,
+    /// word prefix (`adv` in "Game Boy Advance"), substring, then fuzzy. Fuzzy scores are
+    /// normalized Damerau-Levenshtein similarity against each word and the whole name, kept
+    /// at >= 0.7 (`nintedo` -> Nintendo); queries under 3 characters skip fuzzy matching.
+    /// Ties break on name.
     #[tauri::command]
     pub async fn search_platforms(query: String) -> CommandResult<Vec<Platform>> {
         todo!()
     }
 
-    /// Search emulators by query (name).
+    /// Search emulators by query (name), best match first. Same ranking as
+    /// `search_platforms`, over `id`, `name` and `core_name`.
     #[tauri::command]
     pub async fn search_emulators(query: String) -> CommandResult<Vec<Emulator>> {
         todo!()
     }
 
+    /// Pack-recommended emulator for a platform on an OS.
+    ///
+    /// This is the base layer of emulator resolution; `PlatformOverride` and
+    /// `GameOverride.force_emulator_id` take precedence over it.
+    #[tauri::command]
+    pub async fn get_default_emulator(platform_id: String, os_id: String) -> CommandResult<Option<DefaultEmulator>> {
+        todo!()
+    }
+
     /// Get emulators that support a specific platform.
     #[tauri::command]
     pub async fn get_emulators_for_platform(platform_id: String) -> CommandResult<Vec<Emulator>> {
@@ -1419,6 +3960,35 @@ pub mod definitions {
     pub async fn get_chipsets() -> CommandResult<Vec<Chipset>> {
         todo!()
     }
+
+    /// Side-by-side comparison of chipsets with a per-platform capability matrix derived
+    /// from compat performance data (user entries included, ranked as in
+    /// `get_game_performance`). Unknown ids are `NotFound` (resource `chipset`).
+    #[tauri::command]
+    pub async fn compare_chipsets(ids: Vec<String>) -> CommandResult<ChipsetComparison> {
+        todo!()
+    }
+
+    /// Effective destination extension rules (pack rules merged with user overrides),
+    /// optionally narrowed to one frontend.
+    #[tauri::command]
+    pub async fn get_extension_rules(frontend_id: Option<String>) -> CommandResult<Vec<ExtensionRule>> {
+        todo!()
+    }
+
+    /// Filename rules from the loaded pack, optionally narrowed to a frontend and/or OS.
+    #[tauri::command]
+    pub async fn get_name_rules(frontend_id: Option<String>, os_id: Option<String>) -> CommandResult<Vec<NameRule>> {
+        todo!()
+    }
+
+    /// Header signatures used by the scanner, from the loaded pack (seeded with iNES,
+    /// Genesis/Saturn `SEGA` strings, PS1/PS2 ISO volume descriptors and the three N64 byte
+    /// orders), optionally for one platform.
+    #[tauri::command]
+    pub async fn get_header_signatures(platform_id: Option<String>) -> CommandResult<Vec<HeaderSignature>> {
+        todo!()
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -1455,15 +4025,72 @@ pub mod fs {
         todo!()
     }
 
-    /// Calculate a file hash (md5/sha1/sha256/crc32).
+    /// Calculate a file hash (md5/sha1/sha256/crc32). Thin wrapper over
+    /// `calculate_file_hashes` with one algorithm.
+    ///
+    /// Consults the persistent hash cache first; an entry is used only when the file's size
+    /// and mtime still match, otherwise it is recomputed and replaced. Scanning and BIOS
+    /// verification share the same cache.
+    ///
+    /// The file is streamed in chunks. With an `operation_id` (chosen by the caller so it
+    /// can subscribe first), large files emit `hash_progress` and can be stopped with
+    /// `cancel_hash`, which makes this return `Cancelled`. The result is still returned
+    /// directly.
+    #[tauri::command]
+    pub async fn calculate_file_hash(
+        path: String,
+        algorithm: HashAlgorithm,
+        operation_id: Option<String>,
+    ) -> CommandResult<String> {
+        todo!()
+    }
+
+    /// Calculate several hashes in a single read of the file.
+    ///
+    /// Each chunk is fed to every requested hasher in parallel. Cache, progress and cancel
+    /// behave as in `calculate_file_hash`. Scanning and BIOS verification use this path.
+    #[tauri::command]
+    pub async fn calculate_file_hashes(
+        path: String,
+        algorithms: Vec<HashAlgorithm>,
+        operation_id: Option<String>,
+    ) -> CommandResult<HashMap<HashAlgorithm, String>> {
+        todo!()
+    }
+
+    /// Stop an in-flight `calculate_file_hash(es)` started with `operation_id`.
+    #[tauri::command]
+    pub async fn cancel_hash(operation_id: String) -> CommandResult<CancelOutcome> {
+        todo!()
+    }
+
+    /// Drop every persistent hash cache entry. Returns the number removed.
     #[tauri::command]
-    pub async fn calculate_file_hash(path: String, algorithm: HashAlgorithm) -> CommandResult<String> {
+    pub async fn clear_hash_cache() -> CommandResult<u64> {
         todo!()
     }
 
     /// Copy a file.
+    ///
+    /// Zero runs of at least one filesystem block are punched as holes when the destination
+    /// supports sparse files (NTFS, ext4, APFS, ...). FAT32/exFAT fall back to a plain copy.
+    ///
+    /// Data is written to a hidden temp file in the destination directory and renamed over
+    /// the target on success, so an interrupted overwrite leaves the old file intact. The temp
+    /// file is removed on any failure.
+    ///
+    /// The copy is chunked (`CopyOptions.buffer_size`). With an `operation_id` it emits
+    /// `copy_progress` (coalesced like deployment progress) and can be cancelled, which
+    /// deletes the partial temp file and returns `Cancelled`. A verification mismatch is
+    /// not an error: the result has `verified: false` and a warning.
+    #[tauri::command]
+    pub async fn copy_file(source: String, dest: String, options: Option<CopyOptions>) -> CommandResult<CopyResult> {
+        todo!()
+    }
+
+    /// Stop an in-flight `copy_file` started with `operation_id`.
     #[tauri::command]
-    pub async fn copy_file(source: String, dest: String) -> CommandResult<CopyResult> {
+    pub async fn cancel_copy(operation_id: String) -> CommandResult<CancelOutcome> {
         todo!()
     }
 
@@ -1479,9 +4106,28 @@ pub mod fs {
         todo!()
     }
 
+    // Shell integration -------------------------------------------------------
+
+    /// Open the OS file manager with `path` selected (Explorer `/select,`, Finder reveal,
+    /// or the freedesktop FileManager1 D-Bus call with an `xdg-open` parent-dir fallback).
+    ///
+    /// Paths are passed as single arguments, never through a shell, so spaces and unicode are
+    /// safe. Missing paths return `NotFound`; launcher failures return `IoError`.
+    #[tauri::command]
+    pub async fn reveal_in_file_manager(path: String) -> CommandResult<bool> {
+        todo!()
+    }
+
+    /// Open a file or directory with the OS default handler.
+    #[tauri::command]
+    pub async fn open_path(path: String) -> CommandResult<bool> {
+        todo!()
+    }
+
     // Archive operations ------------------------------------------------------
 
-    /// List archive contents (zip/7z/rar as supported by backend).
+    /// List archive contents (zip/7z/rar as supported by backend). A `.chd` lists its CD
+    /// tracks (see `ArchiveEntry.track_type`).
     #[tauri::command]
     pub async fn list_archive_contents(path: String) -> CommandResult<Vec<ArchiveEntry>> {
         todo!()
@@ -0,0 +1,331 @@
+-- ROM Runner SQLite Migration: v1.0.1 -> v1.0.2
+-- Generated: 2026-10-16
+--
+-- Additive: new tables, columns and indexes. deployment_history is rebuilt to widen its
+-- status CHECK; its rows are copied over. No existing data is dropped.
+--
+-- What it does:
+--   * Adds game_tags (auto-derived + user tags per game)
+--   * Adds scan_checkpoints + scan_checkpoint_files (resumable rescans)
+--   * Adds scan_history (persisted ScanResult per rescan)
+--   * Adds destination_fingerprints (weighted device re-identification)
+--   * Adds games.display_title / games.raw_title (backfills both from existing titles)
+--   * Adds games.companion_files (disc sheet track files)
+--   * Adds games.regions (JSON array, seeded from games.region)
+--   * Adds hash_cache (digests keyed by path + size + mtime)
+--   * Adds games.archive_entry (inner ROM hashed for single-ROM archives)
+--   * Adds games.disc_files and games.m3u_path (multi-disc sets)
+--   * Adds games.dat_verified and games.dat_name (DAT matching)
+--   * Adds games_fts (FTS5 title index + sync triggers) and populates it
+--   * Adds games.deleted_at (soft delete / trash)
+--   * Adds games.play_count and games.rating (play tracking)
+--   * Adds games.disc_serial (PS1/PS2 serial from SYSTEM.CNF)
+--   * Adds games.logical_size (uncompressed CHD size)
+--   * Adds deployment_journal / deployment_journal_items (resumable deployments)
+--   * Adds deployment_rollback_files (deployment undo)
+--   * Adds deployment_history.throughput_bps and per_file_overhead_ms (ETA estimates)
+--   * Rebuilds deployment_history to allow status 'interrupted' (resumable deployments)
+--   * Adds user_compat_entries and user_performance_entries (user-owned compat results)
+--   * Adds settings_presets (named settings snapshots)
+--   * Bumps schema_version to 3
+
+BEGIN IMMEDIATE;
+
+PRAGMA foreign_keys = ON;
+
+-- Game tags ------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS game_tags (
+    game_id           INTEGER NOT NULL,
+    tag               TEXT    NOT NULL,
+    source            TEXT    NOT NULL DEFAULT 'user' CHECK (source IN ('auto','user')),
+    added_at          TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (game_id, tag, source),
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+) WITHOUT ROWID;
+
+CREATE INDEX IF NOT EXISTS idx_game_tags_tag ON game_tags(tag);
+
+-- Scan checkpoints -----------------------------------------------------------
+CREATE TABLE IF NOT EXISTS scan_checkpoints (
+    scan_id           TEXT PRIMARY KEY,
+    settings_hash     TEXT    NOT NULL,            -- hash of library roots + scan settings; mismatch => invalid
+    roots_json        TEXT    NOT NULL,            -- JSON array of library roots at scan start
+    processed_files   INTEGER NOT NULL DEFAULT 0,
+    total_files       INTEGER,
+    started_at        TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at        TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS scan_checkpoint_files (
+    scan_id           TEXT    NOT NULL,
+    filepath          TEXT    NOT NULL,
+
+    PRIMARY KEY (scan_id, filepath),
+    FOREIGN KEY (scan_id) REFERENCES scan_checkpoints(scan_id) ON DELETE CASCADE
+) WITHOUT ROWID;
+
+-- Scan history ---------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS scan_history (
+    scan_id           TEXT PRIMARY KEY,
+    status            TEXT    NOT NULL CHECK (status IN ('started','running','completed','cancelled','failed')),
+    started_at        TEXT    NOT NULL,
+    finished_at       TEXT,
+    scanned_files     INTEGER NOT NULL DEFAULT 0,
+    added_games       INTEGER NOT NULL DEFAULT 0,
+    updated_games     INTEGER NOT NULL DEFAULT 0,
+    excluded_count    INTEGER NOT NULL DEFAULT 0,  -- files + pruned dirs skipped by exclude_globs
+    errors_json       TEXT    NOT NULL DEFAULT '[]',  -- JSON array of strings
+    warnings_json     TEXT    NOT NULL DEFAULT '[]'   -- JSON array of strings
+);
+
+CREATE INDEX IF NOT EXISTS idx_scan_history_started_at ON scan_history(started_at);
+
+-- Destination fingerprints ---------------------------------------------------
+CREATE TABLE IF NOT EXISTS destination_fingerprints (
+    destination_id      TEXT PRIMARY KEY,
+    device_row_id       INTEGER,                   -- matched user device, if any
+    partition_uuid      TEXT,
+    fs_serial           TEXT,
+    label               TEXT,
+    total_bytes         INTEGER,
+    last_seen_at        TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    FOREIGN KEY (device_row_id) REFERENCES devices(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_dest_fp_partition_uuid ON destination_fingerprints(partition_uuid);
+CREATE INDEX IF NOT EXISTS idx_dest_fp_fs_serial ON destination_fingerprints(fs_serial);
+
+-- Display vs raw titles -----------------------------------------------------
+ALTER TABLE games ADD COLUMN display_title TEXT;
+-- SQLite needs a default to add a NOT NULL column; every row is backfilled below.
+ALTER TABLE games ADD COLUMN raw_title TEXT NOT NULL DEFAULT '';
+
+-- Existing titles become display titles; raw_title takes the filename-derived
+-- detected_title (or the filename when parsing found none).
+UPDATE games
+SET display_title = COALESCE(
+    (SELECT gm.title FROM game_metadata gm WHERE gm.game_id = games.id AND gm.title IS NOT NULL),
+    detected_title,
+    filename
+)
+WHERE display_title IS NULL;
+
+UPDATE games
+SET raw_title = COALESCE(detected_title, filename)
+WHERE raw_title = '';
+
+CREATE INDEX IF NOT EXISTS idx_games_display_title ON games(display_title);
+CREATE INDEX IF NOT EXISTS idx_games_raw_title ON games(raw_title);
+
+-- Disc sheet companion files -------------------------------------------------
+ALTER TABLE games ADD COLUMN companion_files TEXT;
+
+-- Structured regions ---------------------------------------------------------
+ALTER TABLE games ADD COLUMN regions TEXT;
+
+UPDATE games
+SET regions = json_array(region)
+WHERE regions IS NULL AND region IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_games_region ON games(region);
+
+-- Hash cache -----------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS hash_cache (
+    filepath          TEXT PRIMARY KEY,
+    file_size         INTEGER NOT NULL,
+    mtime             INTEGER NOT NULL,            -- modification time, unix nanoseconds
+    md5               TEXT,
+    sha1              TEXT,
+    crc32             TEXT,
+    sha256            TEXT,
+    computed_at       TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Archive-aware hashing ------------------------------------------------------
+ALTER TABLE games ADD COLUMN archive_entry TEXT;
+
+-- Multi-disc sets ------------------------------------------------------------
+ALTER TABLE games ADD COLUMN disc_files TEXT;
+ALTER TABLE games ADD COLUMN m3u_path TEXT;
+
+-- DAT verification -----------------------------------------------------------
+ALTER TABLE games ADD COLUMN dat_verified INTEGER CHECK (dat_verified IN (0,1));
+ALTER TABLE games ADD COLUMN dat_name TEXT;
+
+-- Title full-text search -----------------------------------------------------
+CREATE VIRTUAL TABLE IF NOT EXISTS games_fts USING fts5(
+    display_title,
+    raw_title,
+    content='games',
+    content_rowid='id',
+    tokenize='unicode61 remove_diacritics 2',
+    prefix='2 3'
+);
+
+CREATE TRIGGER IF NOT EXISTS games_fts_ai AFTER INSERT ON games BEGIN
+    INSERT INTO games_fts(rowid, display_title, raw_title)
+    VALUES (new.id, new.display_title, new.raw_title);
+END;
+
+CREATE TRIGGER IF NOT EXISTS games_fts_ad AFTER DELETE ON games BEGIN
+    INSERT INTO games_fts(games_fts, rowid, display_title, raw_title)
+    VALUES ('delete', old.id, old.display_title, old.raw_title);
+END;
+
+CREATE TRIGGER IF NOT EXISTS games_fts_au AFTER UPDATE OF display_title, raw_title ON games BEGIN
+    INSERT INTO games_fts(games_fts, rowid, display_title, raw_title)
+    VALUES ('delete', old.id, old.display_title, old.raw_title);
+    INSERT INTO games_fts(rowid, display_title, raw_title)
+    VALUES (new.id, new.display_title, new.raw_title);
+END;
+
+INSERT INTO games_fts(games_fts) VALUES ('rebuild');
+
+-- Trash ----------------------------------------------------------------------
+ALTER TABLE games ADD COLUMN deleted_at TEXT;
+CREATE INDEX IF NOT EXISTS idx_games_deleted_at ON games(deleted_at);
+
+-- Play tracking --------------------------------------------------------------
+-- last_played_at already exists; existing rows start at play_count 0, unrated.
+ALTER TABLE games ADD COLUMN play_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE games ADD COLUMN rating INTEGER CHECK (rating BETWEEN 0 AND 5);
+CREATE INDEX IF NOT EXISTS idx_games_play_count ON games(play_count);
+CREATE INDEX IF NOT EXISTS idx_games_rating ON games(rating);
+
+-- Disc serials ---------------------------------------------------------------
+ALTER TABLE games ADD COLUMN disc_serial TEXT;
+CREATE INDEX IF NOT EXISTS idx_games_disc_serial ON games(disc_serial);
+
+-- CHD ------------------------------------------------------------------------
+ALTER TABLE games ADD COLUMN logical_size INTEGER;
+
+-- Resumable deployments ------------------------------------------------------
+CREATE TABLE IF NOT EXISTS deployment_journal (
+    handle_id           TEXT PRIMARY KEY,
+    plan_json           TEXT NOT NULL,             -- full DeploymentPlan as started
+    started_at          TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TEXT
+);
+
+CREATE TABLE IF NOT EXISTS deployment_journal_items (
+    handle_id           TEXT    NOT NULL,
+    item_index          INTEGER NOT NULL,          -- index into plan_json items
+    status              TEXT    NOT NULL DEFAULT 'pending'
+                         CHECK (status IN ('pending','copied','verified')),
+    updated_at          TEXT,
+
+    PRIMARY KEY (handle_id, item_index),
+    FOREIGN KEY (handle_id) REFERENCES deployment_journal(handle_id) ON DELETE CASCADE
+) WITHOUT ROWID;
+
+-- Deployment rollback --------------------------------------------------------
+CREATE TABLE IF NOT EXISTS deployment_rollback_files (
+    handle_id           TEXT    NOT NULL,
+    dest_path           TEXT    NOT NULL,
+    action              TEXT    NOT NULL CHECK (action IN ('created','overwritten','deleted')),
+    backup_path         TEXT,                      -- original moved aside (overwritten/deleted only)
+    recorded_at         TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (handle_id, dest_path)
+) WITHOUT ROWID;
+
+-- Deployment throughput -----------------------------------------------------
+-- NULL for past deployments; ETAs use defaults until a device has a measured run.
+ALTER TABLE deployment_history ADD COLUMN throughput_bps INTEGER;
+ALTER TABLE deployment_history ADD COLUMN per_file_overhead_ms INTEGER;
+
+-- Interrupted deployment status -----------------------------------------------
+-- SQLite can't alter a CHECK constraint, so the table is rebuilt with 'interrupted'
+-- allowed. No other table references deployment_history.
+CREATE TABLE deployment_history_new (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    device_profile_id   INTEGER NOT NULL,
+
+    deployment_name     TEXT,
+    destination_path    TEXT    NOT NULL,
+    destination_id      TEXT,
+
+    totals_json         TEXT,
+    options_json        TEXT,
+    manifest_json       TEXT,
+
+    status              TEXT NOT NULL DEFAULT 'completed'
+                         CHECK (status IN ('started','completed','failed','cancelled','interrupted')),
+    started_at          TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    completed_at        TEXT,
+    error_message       TEXT,
+    throughput_bps      INTEGER,
+    per_file_overhead_ms INTEGER,
+
+    FOREIGN KEY (device_profile_id) REFERENCES device_profiles(id) ON DELETE CASCADE
+);
+
+INSERT INTO deployment_history_new (
+    id, device_profile_id, deployment_name, destination_path, destination_id,
+    totals_json, options_json, manifest_json, status, started_at, completed_at,
+    error_message, throughput_bps, per_file_overhead_ms
+)
+SELECT
+    id, device_profile_id, deployment_name, destination_path, destination_id,
+    totals_json, options_json, manifest_json, status, started_at, completed_at,
+    error_message, throughput_bps, per_file_overhead_ms
+FROM deployment_history;
+
+DROP TABLE deployment_history;
+ALTER TABLE deployment_history_new RENAME TO deployment_history;
+
+CREATE INDEX IF NOT EXISTS idx_deployments_device_profile ON deployment_history(device_profile_id);
+CREATE INDEX IF NOT EXISTS idx_deployments_started_at ON deployment_history(started_at);
+CREATE INDEX IF NOT EXISTS idx_deployments_status ON deployment_history(status);
+
+-- User compatibility entries ------------------------------------------------
+CREATE TABLE IF NOT EXISTS user_compat_entries (
+    platform_id         TEXT    NOT NULL,
+    emulator_id         TEXT    NOT NULL,
+    definition_game_id  TEXT    NOT NULL,
+    status              TEXT    NOT NULL
+                        CHECK (status IN ('perfect','playable','ingame','menu_intro','boots_only','broken','unknown')),
+    notes               TEXT,
+    updated_at          TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (platform_id, emulator_id, definition_game_id)
+) WITHOUT ROWID;
+
+CREATE TABLE IF NOT EXISTS user_performance_entries (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    chipset_id          TEXT    NOT NULL,
+    platform_id         TEXT    NOT NULL,
+    definition_game_id  TEXT    NOT NULL,
+    emulator_id         TEXT,                      -- nullable: applies to any emulator
+    performance_tier    TEXT    NOT NULL
+                        CHECK (performance_tier IN ('excellent','good','playable','poor','unplayable')),
+    requires_settings   INTEGER NOT NULL DEFAULT 0 CHECK (requires_settings IN (0,1)),
+    exclude_from_auto   INTEGER NOT NULL DEFAULT 0 CHECK (exclude_from_auto IN (0,1)),
+    notes               TEXT,
+    updated_at          TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uidx_user_perf_key
+    ON user_performance_entries(chipset_id, platform_id, definition_game_id, COALESCE(emulator_id,''));
+
+-- Settings presets -----------------------------------------------------------
+CREATE TABLE IF NOT EXISTS settings_presets (
+    name              TEXT PRIMARY KEY,
+    settings_json     TEXT NOT NULL,               -- JSON: full AppSettings snapshot
+    created_at        TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at        TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Update schema_version ------------------------------------------------------
+UPDATE schema_version
+SET schema_version = 3,
+    applied_at = CURRENT_TIMESTAMP,
+    notes = 'Migrated to schema v1.0.2'
+WHERE id = 1 AND schema_version < 3;
+
+INSERT OR IGNORE INTO schema_migrations(version, name)
+VALUES (3, 'v1_0_2_library_extensions');
+
+COMMIT;
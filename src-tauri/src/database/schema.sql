@@ -1,4 +1,4 @@
--- ROM Runner Local SQLite Schema v1.0.1
+-- ROM Runner Local SQLite Schema v1.0.2
 -- Generated: 2026-01-04
 -- Target: SQLite 3.x
 --
@@ -61,17 +61,28 @@ CREATE TABLE IF NOT EXISTS games (
     -- Identification (Definition Pack IDs)
     platform_id       TEXT    NOT NULL,            -- references platforms.json id (external)
     detected_title    TEXT,                        -- from filename parsing
+    display_title     TEXT,                        -- cleaned/user-edited title shown in UI (v1.0.2)
+    raw_title         TEXT    NOT NULL DEFAULT '', -- filename-derived title kept for matching (v1.0.2)
     matched_game_id   TEXT,                        -- matched external/definition id (string; may be null)
 
     -- Status
     format            TEXT,                        -- zip, 7z, chd, iso, etc.
     is_archive        INTEGER NOT NULL DEFAULT 0 CHECK (is_archive IN (0,1)),
     archive_contents  TEXT,                        -- JSON array of files in archive (if is_archive=1)
+    archive_entry     TEXT,                        -- inner ROM whose hashes are stored, for single-ROM archives (v1.0.2)
+    dat_verified      INTEGER CHECK (dat_verified IN (0,1)), -- last DAT match result; NULL = never checked (v1.0.2)
+    dat_name          TEXT,                        -- canonical DAT name from the last match (v1.0.2)
+    logical_size      INTEGER,                     -- uncompressed data size for CHD images (v1.0.2)
+    disc_serial       TEXT,                        -- PS1/PS2 boot serial from SYSTEM.CNF, e.g. SLUS-00594 (v1.0.2)
+    companion_files   TEXT,                        -- JSON array of track files referenced by a .cue/.gdi/.ccd (v1.0.2)
+    disc_files        TEXT,                        -- JSON array of every disc path of a multi-disc set, in order (v1.0.2)
+    m3u_path          TEXT,                        -- .m3u playlist listing disc_files (v1.0.2)
     needs_conversion  INTEGER NOT NULL DEFAULT 0 CHECK (needs_conversion IN (0,1)),
     is_missing        INTEGER NOT NULL DEFAULT 0 CHECK (is_missing IN (0,1)), -- file missing from disk but record retained
 
     -- Metadata (lightweight parsing)
     region            TEXT,                        -- e.g. USA/EUR/JPN/World/Unknown
+    regions           TEXT,                        -- JSON array of Region values, in tag order (v1.0.2)
     languages         TEXT,                        -- JSON array
     revision          TEXT,
     is_hack           INTEGER NOT NULL DEFAULT 0 CHECK (is_hack IN (0,1)),
@@ -80,7 +91,10 @@ CREATE TABLE IF NOT EXISTS games (
     -- Timestamps
     added_at          TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
     last_scanned_at   TEXT,
-    last_played_at    TEXT
+    last_played_at    TEXT,
+    play_count        INTEGER NOT NULL DEFAULT 0,  -- launches recorded via record_play (v1.0.2)
+    rating            INTEGER CHECK (rating BETWEEN 0 AND 5), -- user rating; NULL = unrated (v1.0.2)
+    deleted_at        TEXT                         -- set while soft-deleted (in the trash) (v1.0.2)
 );
 
 CREATE TABLE IF NOT EXISTS game_metadata (
@@ -132,6 +146,57 @@ CREATE TABLE IF NOT EXISTS collection_games (
     FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
 ) WITHOUT ROWID;
 
+-- Tags per game. source='auto' rows are derived from filename flags and are replaced on
+-- rescan; source='user' rows are never touched by the scanner. (v1.0.2)
+CREATE TABLE IF NOT EXISTS game_tags (
+    game_id           INTEGER NOT NULL,
+    tag               TEXT    NOT NULL,
+    source            TEXT    NOT NULL DEFAULT 'user' CHECK (source IN ('auto','user')),
+    added_at          TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (game_id, tag, source),
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+) WITHOUT ROWID;
+
+CREATE INDEX IF NOT EXISTS idx_game_tags_tag ON game_tags(tag);
+
+-- One row per rescan_library run (full errors/warnings kept). Pruned by
+-- ScanSettings.scanHistoryRetentionDays. (v1.0.2)
+CREATE TABLE IF NOT EXISTS scan_history (
+    scan_id           TEXT PRIMARY KEY,
+    status            TEXT    NOT NULL CHECK (status IN ('started','running','completed','cancelled','failed')),
+    started_at        TEXT    NOT NULL,
+    finished_at       TEXT,
+    scanned_files     INTEGER NOT NULL DEFAULT 0,
+    added_games       INTEGER NOT NULL DEFAULT 0,
+    updated_games     INTEGER NOT NULL DEFAULT 0,
+    excluded_count    INTEGER NOT NULL DEFAULT 0,  -- files + pruned dirs skipped by exclude_globs
+    errors_json       TEXT    NOT NULL DEFAULT '[]',  -- JSON array of strings
+    warnings_json     TEXT    NOT NULL DEFAULT '[]'   -- JSON array of strings
+);
+
+CREATE INDEX IF NOT EXISTS idx_scan_history_started_at ON scan_history(started_at);
+
+-- Periodic checkpoints of an in-progress rescan_library so it can resume after a restart.
+-- Rows are deleted when the scan completes or is cancelled. (v1.0.2)
+CREATE TABLE IF NOT EXISTS scan_checkpoints (
+    scan_id           TEXT PRIMARY KEY,
+    settings_hash     TEXT    NOT NULL,            -- hash of library roots + scan settings; mismatch => invalid
+    roots_json        TEXT    NOT NULL,            -- JSON array of library roots at scan start
+    processed_files   INTEGER NOT NULL DEFAULT 0,
+    total_files       INTEGER,
+    started_at        TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at        TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS scan_checkpoint_files (
+    scan_id           TEXT    NOT NULL,
+    filepath          TEXT    NOT NULL,
+
+    PRIMARY KEY (scan_id, filepath),
+    FOREIGN KEY (scan_id) REFERENCES scan_checkpoints(scan_id) ON DELETE CASCADE
+) WITHOUT ROWID;
+
 -- ---------------------------------------------------------------------------
 --  Device management
 -- ---------------------------------------------------------------------------
@@ -195,10 +260,12 @@ CREATE TABLE IF NOT EXISTS deployment_history (
     manifest_json       TEXT,                      -- JSON: transfer manifest (files + hashes)
 
     status              TEXT NOT NULL DEFAULT 'completed'
-                         CHECK (status IN ('started','completed','failed','cancelled')),
+                         CHECK (status IN ('started','completed','failed','cancelled','interrupted')),
     started_at          TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
     completed_at        TEXT,
     error_message       TEXT,
+    throughput_bps      INTEGER,                   -- measured copy throughput, for ETAs (v1.0.2)
+    per_file_overhead_ms INTEGER,                  -- measured fixed cost per file (v1.0.2)
 
     FOREIGN KEY (device_profile_id) REFERENCES device_profiles(id) ON DELETE CASCADE
 );
@@ -207,6 +274,38 @@ CREATE INDEX IF NOT EXISTS idx_deployments_device_profile ON deployment_history(
 CREATE INDEX IF NOT EXISTS idx_deployments_started_at ON deployment_history(started_at);
 CREATE INDEX IF NOT EXISTS idx_deployments_status ON deployment_history(status);
 
+-- Durable per-item state of a running deployment, so it can resume after a crash or
+-- unplug. Rows are removed when the deployment finishes. (v1.0.2)
+CREATE TABLE IF NOT EXISTS deployment_journal (
+    handle_id           TEXT PRIMARY KEY,
+    plan_json           TEXT NOT NULL,             -- full DeploymentPlan as started
+    started_at          TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TEXT
+);
+
+CREATE TABLE IF NOT EXISTS deployment_journal_items (
+    handle_id           TEXT    NOT NULL,
+    item_index          INTEGER NOT NULL,          -- index into plan_json items
+    status              TEXT    NOT NULL DEFAULT 'pending'
+                         CHECK (status IN ('pending','copied','verified')),
+    updated_at          TEXT,
+
+    PRIMARY KEY (handle_id, item_index),
+    FOREIGN KEY (handle_id) REFERENCES deployment_journal(handle_id) ON DELETE CASCADE
+) WITHOUT ROWID;
+
+-- Files a deployment created, overwrote or deleted, for rollback_deployment. Cleared on
+-- confirm_deployment or when the rollback window expires. (v1.0.2)
+CREATE TABLE IF NOT EXISTS deployment_rollback_files (
+    handle_id           TEXT    NOT NULL,
+    dest_path           TEXT    NOT NULL,
+    action              TEXT    NOT NULL CHECK (action IN ('created','overwritten','deleted')),
+    backup_path         TEXT,                      -- original moved aside (overwritten/deleted only)
+    recorded_at         TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (handle_id, dest_path)
+) WITHOUT ROWID;
+
 -- Destination scanning / per-destination overrides (v2.5 requirements support)
 CREATE TABLE IF NOT EXISTS destination_path_overrides (
     destination_id      TEXT PRIMARY KEY,          -- stable destination identifier (volume UUID or user-defined)
@@ -217,6 +316,23 @@ CREATE TABLE IF NOT EXISTS destination_path_overrides (
     updated_at          TEXT
 );
 
+-- Identity signals of previously-seen destinations, used to re-identify a card whose
+-- device path or label changed. (v1.0.2)
+CREATE TABLE IF NOT EXISTS destination_fingerprints (
+    destination_id      TEXT PRIMARY KEY,
+    device_row_id       INTEGER,                   -- matched user device, if any
+    partition_uuid      TEXT,
+    fs_serial           TEXT,
+    label               TEXT,
+    total_bytes         INTEGER,
+    last_seen_at        TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    FOREIGN KEY (device_row_id) REFERENCES devices(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_dest_fp_partition_uuid ON destination_fingerprints(partition_uuid);
+CREATE INDEX IF NOT EXISTS idx_dest_fp_fs_serial ON destination_fingerprints(fs_serial);
+
 CREATE TABLE IF NOT EXISTS destination_scan_history (
     id                  INTEGER PRIMARY KEY AUTOINCREMENT,
     destination_id      TEXT NOT NULL,
@@ -239,6 +355,14 @@ CREATE TABLE IF NOT EXISTS user_settings (
     updated_at        TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
 );
 
+-- Named AppSettings snapshots; the active one is user_settings key 'active_preset'. (v1.0.2)
+CREATE TABLE IF NOT EXISTS settings_presets (
+    name              TEXT PRIMARY KEY,
+    settings_json     TEXT NOT NULL,               -- JSON: full AppSettings snapshot
+    created_at        TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at        TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
 -- Per-platform emulator preference. device_profile_id NULL => global override.
 CREATE TABLE IF NOT EXISTS platform_overrides (
     id                INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -397,6 +521,37 @@ CREATE INDEX IF NOT EXISTS idx_settings_cache_lookup
 CREATE INDEX IF NOT EXISTS idx_settings_cache_prune
     ON game_settings_cache(expires_at, cached_at);
 
+-- User-entered results (source = 'user'). Separate from the caches so pack updates and
+-- refresh_compatibility_cache never touch them. (v1.0.2)
+CREATE TABLE IF NOT EXISTS user_compat_entries (
+    platform_id         TEXT    NOT NULL,
+    emulator_id         TEXT    NOT NULL,
+    definition_game_id  TEXT    NOT NULL,
+    status              TEXT    NOT NULL
+                        CHECK (status IN ('perfect','playable','ingame','menu_intro','boots_only','broken','unknown')),
+    notes               TEXT,
+    updated_at          TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (platform_id, emulator_id, definition_game_id)
+) WITHOUT ROWID;
+
+CREATE TABLE IF NOT EXISTS user_performance_entries (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    chipset_id          TEXT    NOT NULL,
+    platform_id         TEXT    NOT NULL,
+    definition_game_id  TEXT    NOT NULL,
+    emulator_id         TEXT,                      -- nullable: applies to any emulator
+    performance_tier    TEXT    NOT NULL
+                        CHECK (performance_tier IN ('excellent','good','playable','poor','unplayable')),
+    requires_settings   INTEGER NOT NULL DEFAULT 0 CHECK (requires_settings IN (0,1)),
+    exclude_from_auto   INTEGER NOT NULL DEFAULT 0 CHECK (exclude_from_auto IN (0,1)),
+    notes               TEXT,
+    updated_at          TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uidx_user_perf_key
+    ON user_performance_entries(chipset_id, platform_id, definition_game_id, COALESCE(emulator_id,''));
+
 
 
 --  BIOS management
@@ -446,15 +601,68 @@ CREATE UNIQUE INDEX IF NOT EXISTS uidx_bios_verify_key
 CREATE INDEX IF NOT EXISTS idx_bios_verify_definition
     ON bios_verification(bios_definition_id);
 
+-- ---------------------------------------------------------------------------
+--  Hash cache (v1.0.2)
+--  Digests keyed by path; a row is only valid while file_size and mtime still match.
+-- ---------------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS hash_cache (
+    filepath          TEXT PRIMARY KEY,
+    file_size         INTEGER NOT NULL,
+    mtime             INTEGER NOT NULL,            -- modification time, unix nanoseconds
+    md5               TEXT,
+    sha1              TEXT,
+    crc32             TEXT,
+    sha256            TEXT,
+    computed_at       TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- ---------------------------------------------------------------------------
+--  Title full-text search (v1.0.2)
+--  External-content FTS5 index over games; kept in sync by triggers.
+--  unicode61 drops punctuation, so "zelda" matches "The Legend of Zelda: ...".
+-- ---------------------------------------------------------------------------
+CREATE VIRTUAL TABLE IF NOT EXISTS games_fts USING fts5(
+    display_title,
+    raw_title,
+    content='games',
+    content_rowid='id',
+    tokenize='unicode61 remove_diacritics 2',
+    prefix='2 3'
+);
+
+CREATE TRIGGER IF NOT EXISTS games_fts_ai AFTER INSERT ON games BEGIN
+    INSERT INTO games_fts(rowid, display_title, raw_title)
+    VALUES (new.id, new.display_title, new.raw_title);
+END;
+
+CREATE TRIGGER IF NOT EXISTS games_fts_ad AFTER DELETE ON games BEGIN
+    INSERT INTO games_fts(games_fts, rowid, display_title, raw_title)
+    VALUES ('delete', old.id, old.display_title, old.raw_title);
+END;
+
+CREATE TRIGGER IF NOT EXISTS games_fts_au AFTER UPDATE OF display_title, raw_title ON games BEGIN
+    INSERT INTO games_fts(games_fts, rowid, display_title, raw_title)
+    VALUES ('delete', old.id, old.display_title, old.raw_title);
+    INSERT INTO games_fts(rowid, display_title, raw_title)
+    VALUES (new.id, new.display_title, new.raw_title);
+END;
+
 -- ---------------------------------------------------------------------------
 --  Indexes for library search/sort
 -- ---------------------------------------------------------------------------
 CREATE INDEX IF NOT EXISTS idx_games_platform_id ON games(platform_id);
 CREATE INDEX IF NOT EXISTS idx_games_filepath ON games(filepath);
 CREATE INDEX IF NOT EXISTS idx_games_detected_title ON games(detected_title);
+CREATE INDEX IF NOT EXISTS idx_games_display_title ON games(display_title);
+CREATE INDEX IF NOT EXISTS idx_games_raw_title ON games(raw_title);
+CREATE INDEX IF NOT EXISTS idx_games_region ON games(region);
 CREATE INDEX IF NOT EXISTS idx_games_matched_game_id ON games(matched_game_id);
 CREATE INDEX IF NOT EXISTS idx_games_added_at ON games(added_at);
 CREATE INDEX IF NOT EXISTS idx_games_last_played_at ON games(last_played_at);
+CREATE INDEX IF NOT EXISTS idx_games_deleted_at ON games(deleted_at);
+CREATE INDEX IF NOT EXISTS idx_games_disc_serial ON games(disc_serial);
+CREATE INDEX IF NOT EXISTS idx_games_play_count ON games(play_count);
+CREATE INDEX IF NOT EXISTS idx_games_rating ON games(rating);
 
 -- ---------------------------------------------------------------------------
 --  v1.0.1 additional indexes for common lookup paths (hash based matching, scans)
@@ -473,7 +681,7 @@ CREATE INDEX IF NOT EXISTS idx_bios_files_crc32    ON bios_files(file_crc32);
 --  Initialize schema_version row (id=1) if absent
 -- ---------------------------------------------------------------------------
 INSERT INTO schema_version (id, schema_version, app_version, notes)
-SELECT 1, 2, NULL, 'Initial SQLite schema v1.0.1 (cache dedupe + hash indexes + optional TTL)'
+SELECT 1, 3, NULL, 'Initial SQLite schema v1.0.2'
 WHERE NOT EXISTS (SELECT 1 FROM schema_version WHERE id = 1);
 
 COMMIT;
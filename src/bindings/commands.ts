@@ -18,8 +18,16 @@ export type CommandError =
   | { type: 'InvalidInput'; field: string; message: string }
   | { type: 'IoError'; path: string; message: string }
   | { type: 'DatabaseError'; message: string }
+  | { type: 'MigrationFailed'; version: number; message: string; backup_path?: string | null }
   | { type: 'DeviceNotConnected'; device_id: string }
   | { type: 'DeploymentFailed'; reason: string }
+  | { type: 'ReadOnlyFilesystem'; path: string }
+  | { type: 'DiskFull'; path: string; required_bytes: number; available_bytes: number }
+  | { type: 'PermissionDenied'; path: string }
+  | { type: 'HostKeyUnknown'; host: string; fingerprint: string }
+  | { type: 'HostKeyMismatch'; host: string; expected: string; actual: string }
+  | { type: 'AuthenticationFailed'; host: string; message: string }
+  | { type: 'ToolNotFound'; tool: string; searched: string[] }
   | { type: 'Cancelled' };
 
 export function isCommandError(err: unknown): err is CommandError {
@@ -41,6 +49,12 @@ async function invokeCommand<T>(cmd: string, args?: Record<string, any>): Promis
   }
 }
 
+/** Batch convention: per-item apply, failures collected; `allOrNothing` rolls back on any failure. */
+export interface BatchFailure {
+  id: number;
+  error: string;
+}
+
 // --------------------------------- Events ------------------------------------
 
 export const EVENTS = {
@@ -50,14 +64,23 @@ export const EVENTS = {
   deploymentComplete: 'deployment_complete',
   deviceConnected: 'device_connected',
   deviceDisconnected: 'device_disconnected',
+  hashProgress: 'hash_progress',
+  copyProgress: 'copy_progress',
+  deploymentQueueDrained: 'deployment_queue_drained',
+  libraryChanged: 'library_changed',
+  definitionsReloaded: 'definitions_reloaded',
+  definitionsReloadFailed: 'definitions_reload_failed',
+  packDownloadProgress: 'pack_download_progress',
 } as const;
 
 // Event payloads
-export type ScanKind = 'library' | 'bios' | 'destination';
+export type ScanKind = 'library' | 'bios' | 'destination' | 'integrity';
 
 export interface ScanProgress {
   scanId: string;
+  /** Strictly increasing, even with parallel hashing. */
   current: number;
+  /** Fixed up front by a counting pass. */
   total: number;
   currentFile: string;
   message?: string | null;
@@ -75,22 +98,102 @@ export interface DeploymentProgress {
   current: number;
   total: number;
   currentFile: string;
+  currentFileBytes: number;
+  currentFileTotal: number;
+  activeFiles: number;
+  /** Cumulative, including every partial in-flight file. */
   bytesTransferred: number;
   speedBps: number;
+  /** Rolling estimate; null until the first speed sample. */
+  etaSecs?: number | null;
+  stage: DeploymentStage;
   message?: string | null;
 }
 
+export type DeploymentStage = 'copying' | 'compressing' | 'verifying';
+
 export interface DeploymentComplete {
   handleId: string;
   success: boolean;
   errors: string[];
   warnings: string[];
+  verification?: VerificationStats | null;
+  copyStats: CopyStats;
+  /** `pullSaves` only. */
+  saveConflicts: SaveConflict[];
+}
+
+export interface SaveConflict {
+  gameId: number;
+  devicePath: string;
+  localPath: string;
+  deviceModified: string;
+  localModified: string;
+}
+
+export interface CopyStats {
+  concurrency: number;
+  aggregateSpeedBps: number;
+  /** aggregate / perFile ~ 1 => more workers didn't help. */
+  perFileSpeedBps: number;
+}
+
+export interface VerificationStats {
+  filesVerified: number;
+  mismatches: number;
+  recovered: number;
+  bytesRead: number;
+  speedBps: number;
+}
+
+/** Renames arrive as removed + added. */
+export interface LibraryChanged {
+  added: string[];
+  removed: string[];
+  modified: string[];
+  rootsUnavailable: string[];
+  rootsAvailable: string[];
+}
+
+/** The previous pack stays loaded. */
+export interface DefinitionsReloadFailed {
+  path: string;
+  message: string;
+}
+
+export interface PackDownloadProgress {
+  version: string;
+  bytes: number;
+  total: number;
+}
+
+export interface QueueDrained {
+  completed: number;
+  failed: number;
+  cancelled: number;
 }
 
 export interface DeviceEvent {
   device: DetectedDevice;
 }
 
+export interface CopyProgress {
+  operationId: string;
+  source: string;
+  dest: string;
+  bytesTransferred: number;
+  totalBytes: number;
+  speedBps: number;
+}
+
+/** Only for files >= 256 MiB hashed with an operation id. */
+export interface HashProgress {
+  operationId: string;
+  path: string;
+  bytes: number;
+  total: number;
+}
+
 // Listener helpers
 export async function onScanProgress(handler: (payload: ScanProgress) => void): Promise<UnlistenFn> {
   return listen<ScanProgress>(EVENTS.scanProgress, (e) => handler(e.payload));
@@ -116,8 +219,43 @@ export async function onDeviceDisconnected(handler: (payload: DeviceEvent) => vo
   return listen<DeviceEvent>(EVENTS.deviceDisconnected, (e) => handler(e.payload));
 }
 
+export async function onCopyProgress(handler: (payload: CopyProgress) => void): Promise<UnlistenFn> {
+  return listen<CopyProgress>(EVENTS.copyProgress, (e) => handler(e.payload));
+}
+
+export async function onHashProgress(handler: (payload: HashProgress) => void): Promise<UnlistenFn> {
+  return listen<HashProgress>(EVENTS.hashProgress, (e) => handler(e.payload));
+}
+
+export async function onDeploymentQueueDrained(handler: (payload: QueueDrained) => void): Promise<UnlistenFn> {
+  return listen<QueueDrained>(EVENTS.deploymentQueueDrained, (e) => handler(e.payload));
+}
+
+export async function onLibraryChanged(handler: (payload: LibraryChanged) => void): Promise<UnlistenFn> {
+  return listen<LibraryChanged>(EVENTS.libraryChanged, (e) => handler(e.payload));
+}
+
+export async function onDefinitionsReloaded(handler: (payload: DefinitionPackMeta) => void): Promise<UnlistenFn> {
+  return listen<DefinitionPackMeta>(EVENTS.definitionsReloaded, (e) => handler(e.payload));
+}
+
+export async function onDefinitionsReloadFailed(handler: (payload: DefinitionsReloadFailed) => void): Promise<UnlistenFn> {
+  return listen<DefinitionsReloadFailed>(EVENTS.definitionsReloadFailed, (e) => handler(e.payload));
+}
+
+export async function onPackDownloadProgress(handler: (payload: PackDownloadProgress) => void): Promise<UnlistenFn> {
+  return listen<PackDownloadProgress>(EVENTS.packDownloadProgress, (e) => handler(e.payload));
+}
+
 // ------------------------------- Core Types ----------------------------------
 
+// ---- Cancellation ----
+
+export type CancelOutcome =
+  | { outcome: 'cancelled'; id: string }
+  | { outcome: 'nothing_running' }
+  | { outcome: 'already_finished' };
+
 // ---- Library ----
 
 export interface ScannedGame {
@@ -131,6 +269,37 @@ export interface ScannedGame {
   crc32?: string | null;
   sha1?: string | null;
   sha256?: string | null;
+  autoTags: string[];
+  isHack: boolean;
+  regions: Region[];
+  languages: string[];
+  revision?: string | null;
+  companionFiles: string[];
+  /** Inner ROM hashed when `filepath` is a single-ROM archive. */
+  archiveEntry?: string | null;
+  platformDetection: PlatformDetection;
+  /** Uncompressed CHD size; `fileSize` stays on-disk. */
+  logicalSize?: number | null;
+  /** PS1/PS2 serial from SYSTEM.CNF, e.g. `SLUS-00594`. */
+  discSerial?: string | null;
+  warnings: string[];
+  discFiles: string[];
+}
+
+export type PlatformSource = 'header' | 'extension';
+
+export interface PlatformDetection {
+  source: PlatformSource;
+  confidence: Confidence;
+  /** Extension's guess when the header disagreed. */
+  extensionPlatformId?: string | null;
+}
+
+export type TagSource = 'auto' | 'user';
+
+export interface GameTag {
+  name: string;
+  source: TagSource;
 }
 
 export type ScanStatus = 'started' | 'running' | 'completed' | 'cancelled' | 'failed';
@@ -145,6 +314,22 @@ export interface ScanResult {
   updatedGames: number;
   errors: string[];
   warnings: string[];
+  /** Files + pruned directories skipped by `excludeGlobs`. */
+  excludedCount: number;
+}
+
+export interface ExcludedPath {
+  path: string;
+  pattern: string;
+}
+
+export interface ScanCheckpoint {
+  scanId: string;
+  roots: string[];
+  processedFiles: number;
+  totalFiles?: number | null;
+  startedAt: string;
+  updatedAt: string;
 }
 
 export interface GameFilter {
@@ -153,6 +338,11 @@ export interface GameFilter {
   searchQuery?: string;
   hasMetadata?: boolean;
   isHack?: boolean;
+  tags?: string[];
+  regions?: Region[];
+  languages?: string[];
+  isMultiDisc?: boolean;
+  includeTrashed?: boolean;
 }
 
 export type SortOrder = 'asc' | 'desc';
@@ -162,6 +352,8 @@ export interface Pagination {
   pageSize: number;
   sortBy: string;
   sortOrder: SortOrder;
+  /** Keyset cursor from `PaginatedGames.nextCursor`; overrides `page`. */
+  cursor?: string | null;
 }
 
 export interface PaginatedGames {
@@ -170,11 +362,19 @@ export interface PaginatedGames {
   pageSize: number;
   totalItems: number;
   totalPages: number;
+  nextCursor?: string | null;
+  facets?: Facets | null;
+}
+
+export interface Facets {
+  perPlatform: Record<string, number>;
+  perRegion: Record<string, number>;
 }
 
 export interface Game {
   id: number;
-  title: string;
+  displayTitle: string;
+  rawTitle: string;
   platformId: string;
   filepath: string;
   filename: string;
@@ -184,24 +384,117 @@ export interface Game {
   crc32?: string | null;
   sha1?: string | null;
   sha256?: string | null;
+  companionFiles: string[];
+  archiveEntry?: string | null;
+  /** Last DAT match result; null = never checked. */
+  verified?: boolean | null;
+  datName?: string | null;
+  discSerial?: string | null;
+  logicalSize?: number | null;
+  /** Every disc of a multi-disc set, in order; empty otherwise. */
+  discFiles: string[];
+  m3uPath?: string | null;
   hasMetadata: boolean;
   isHack: boolean;
+  tags: GameTag[];
+  regions: Region[];
+  languages: string[];
+  revision?: string | null;
+  playCount: number;
+  lastPlayed?: string | null;
+  /** 0-5 */
+  rating?: number | null;
   metadata?: any | null;
   createdAt: string;
   updatedAt: string;
+  /** Set while in the trash. */
+  deletedAt?: string | null;
 }
 
+export type MetadataStrategy = 'replace' | 'merge_shallow' | 'merge_deep';
+
 export interface GameUpdate {
-  title?: string | null;
+  displayTitle?: string | null;
   platformId?: string | null;
   hasMetadata?: boolean | null;
   isHack?: boolean | null;
   metadata?: any | null;
+  /** Default: `replace` (`updateGame`), `merge_shallow` (`updateGames`). */
+  metadataStrategy?: MetadataStrategy | null;
+  userTags?: string[] | null;
+  /** 0-5; null/omitted leaves it unchanged. */
+  rating?: number | null;
+  /** true removes the rating; can't be combined with `rating`. */
+  clearRating?: boolean | null;
 }
 
 export interface DeleteResult {
   deletedCount: number;
-  failedIds: number[];
+  /** Includes games whose files could not be removed; their rows are kept. */
+  failures: BatchFailure[];
+  rolledBack: boolean;
+  /** Refused: a file lies outside the library roots. */
+  skippedIds: number[];
+}
+
+export type LibraryIssueKind =
+  | 'missing_file'
+  | 'missing_hashes'
+  | 'unknown_platform'
+  | 'no_metadata'
+  | 'duplicate'
+  | 'integrity_failed';
+
+export interface LibraryIssueGroup {
+  kind: LibraryIssueKind;
+  count: number;
+  gameIds: number[];
+  needsRefresh: boolean;
+  lastCheckedAt?: string | null;
+}
+
+export interface LibraryIssuesReport {
+  generatedAt: string;
+  totalGames: number;
+  affectedGames: number;
+  groups: LibraryIssueGroup[];
+}
+
+export interface SizeMismatch {
+  gameId: number;
+  expectedBytes: number;
+  actualBytes: number;
+}
+
+export interface IntegrityReport {
+  scanId: string;
+  checked: number;
+  ok: number[];
+  missing: number[];
+  sizeChanged: SizeMismatch[];
+  startedAt: string;
+  finishedAt: string;
+}
+
+export interface DuplicateGroup {
+  hash: string;
+  algorithm: HashAlgorithm;
+  /** Best keeper first. */
+  games: Game[];
+  suggestedKeep: number;
+}
+
+export interface BackfillResult {
+  examined: number;
+  updated: number;
+  errors: string[];
+}
+
+export interface DatMatch {
+  canonicalName?: string | null;
+  region?: Region | null;
+  verified: boolean;
+  matchedBy?: HashAlgorithm | null;
 }
 
 // ---- Collections ----
@@ -209,13 +502,39 @@ export interface DeleteResult {
 export interface Collection {
   id: number;
   name: string;
+  /** Live membership for smart collections. */
   gameIds: number[];
+  isSmart: boolean;
+  filter?: GameFilter | null;
   createdAt: string;
   updatedAt: string;
 }
 
 export interface CollectionUpdate {
   name?: string | null;
+  filter?: GameFilter | null;
+}
+
+export interface GameBatchResult {
+  updated: Game[];
+  failures: BatchFailure[];
+  rolledBack: boolean;
+}
+
+export interface CollectionBatchResult {
+  collection: Collection;
+  failures: BatchFailure[];
+  rolledBack: boolean;
+}
+
+export type PlaylistFormat = 'retro_arch_lpl' | 'es_collection' | 'm3u';
+
+export interface PlaylistExportResult {
+  path: string;
+  format: PlaylistFormat;
+  exported: number;
+  skippedGameIds: number[];
+  warnings: string[];
 }
 
 // ---- Devices / Destinations ----
@@ -228,6 +547,30 @@ export interface DetectedDevice {
   totalBytes?: number | null;
   freeBytes?: number | null;
   isRemovable?: boolean | null;
+  signals: DeviceSignals;
+  matched?: DeviceMatch | null;
+  transport: DeviceTransport;
+}
+
+/** Adb devices use `adb://<serial>/<path>` mount points. */
+export type DeviceTransport =
+  | { kind: 'volume' }
+  | { kind: 'adb'; serial: string; model?: string | null };
+
+export interface DeviceSignals {
+  partitionUuid?: string | null;
+  fsSerial?: string | null;
+  label?: string | null;
+  totalBytes?: number | null;
+  devicePath?: string | null;
+}
+
+export interface DeviceMatch {
+  destinationId: string;
+  userDeviceId?: number | null;
+  score: number;
+  confidence: Confidence;
+  matchedSignals: string[];
 }
 
 export type Confidence = 'high' | 'medium' | 'low' | 'unknown';
@@ -240,6 +583,17 @@ export interface LayoutPaths {
   screenshots?: string | null;
 }
 
+export interface LayoutTemplate {
+  osId: string;
+  /** Relative to the destination root. */
+  paths: LayoutPaths;
+  markers: string[];
+  /** Platform id -> ROM subfolder under `paths.roms`. */
+  romFolders: Record<string, string>;
+  caseSensitive: boolean;
+  notes?: string | null;
+}
+
 export interface DestinationScanResult {
   destinationPath: string;
   destinationId?: string | null;
@@ -258,6 +612,21 @@ export interface LayoutDetectionResult {
   detectedPaths?: LayoutPaths | null;
 }
 
+export interface InstalledEmulator {
+  emulator: Emulator;
+  confidence: Confidence;
+  evidence: string[];
+}
+
+export interface InstalledEmulatorsResult {
+  destinationPath: string;
+  osId: string;
+  /** False => the OS keeps emulators off the card; treat as unknown. */
+  emulatorsOnDestination: boolean;
+  emulators: InstalledEmulator[];
+  notes: string[];
+}
+
 export type ResolvedPathSource = 'expected' | 'detected' | 'user_override' | 'merged';
 export type PathResolutionSource = 'profile' | 'detected' | 'user' | 'fallback';
 
@@ -341,7 +710,7 @@ export interface ProfileUpdate {
 
 // ---- Deploy ----
 
-export type DeploymentItemKind = 'rom' | 'bios' | 'save' | 'state' | 'media' | 'metadata' | 'other';
+export type DeploymentItemKind = 'rom' | 'bios' | 'save' | 'state' | 'media' | 'metadata' | 'other' | 'delete';
 
 export interface DeploymentConfig {
   deviceProfileId: number;
@@ -350,8 +719,49 @@ export interface DeploymentConfig {
   includeBios: boolean;
   includeSaves: boolean;
   includeStates: boolean;
+  /** @deprecated Use `conflictStrategy`; only read when that is null. */
   overwriteExisting: boolean;
-}
+  conflictStrategy?: ConflictStrategy | null;
+  normalizeExtensions: boolean;
+  collectionAsFolder?: number | null;
+  convertToChd: boolean;
+  verifyAfterCopy: boolean;
+  retryOnMismatch: boolean;
+  forceRecopy: boolean;
+  /** Default 4. */
+  copyConcurrency?: number | null;
+  syncMode: SyncMode;
+  /** Split >4 GiB files for FAT32 when the emulator supports it; otherwise validation fails. */
+  splitOversized: boolean;
+  /** Apply `NameIssue.suggested` names when planning. */
+  sanitizeNames: boolean;
+  /** Non-copy modes fall back to copying (with a warning) across filesystems. */
+  linkMode: LinkMode;
+  /** One `gamelist.xml` per platform, as `metadata` plan items. */
+  generateGamelist: boolean;
+  includeMedia: boolean;
+  /** null = from the profile's frontend. */
+  mediaLayout?: MediaLayout | null;
+}
+
+export type MediaLayout = 'retroarch_thumbnails' | 'esde_downloaded_media' | 'rom_folder_media';
+
+export type LinkMode = 'copy' | 'hardlink' | 'symlink' | 'reflink';
+
+export type SplitFormat = 'wbfs' | 'parts';
+
+/** Identical files are skipped under every strategy unless `forceRecopy`. */
+export type ConflictStrategy =
+  | 'skip'
+  | 'overwrite'
+  | 'overwrite_if_newer'
+  | 'overwrite_if_different_hash'
+  | 'rename';
+
+export type ConflictAction = 'overwrite' | 'skip' | 'rename';
+
+/** `mirror` deletes orphaned files under the resolved ROM path only. */
+export type SyncMode = 'additive' | 'mirror';
 
 export interface DeploymentItem {
   kind: DeploymentItemKind;
@@ -360,16 +770,109 @@ export interface DeploymentItem {
   bytes: number;
   platformId?: string | null;
   gameId?: number | null;
+  renamedFrom?: string | null;
+  /** e.g. `chd`; `bytes` is then the compressed estimate. */
+  convertTo?: string | null;
+  /** Present items are shown but not copied. */
+  skipReason?: SkipReason | null;
+  /** `destPath` is the first part; `bytes` is the full size. */
+  split?: SplitFormat | null;
+  /** Set when `destPath` already existed. */
+  conflict?: ConflictAction | null;
+  /** Linked instead of copied; `bytes` is then 0. */
+  link?: LinkMode | null;
+}
+
+export type SkipReason = 'identical' | 'exists' | 'not_newer';
+
+export type DiffChange = 'new' | 'overwrite' | 'identical' | 'delete';
+
+export interface DiffEntry {
+  item: DeploymentItem;
+  change: DiffChange;
+}
+
+export interface DiffTotals {
+  files: number;
+  bytes: number;
+}
+
+export interface DeploymentDiff {
+  planId: string;
+  entries: DiffEntry[];
+  new: DiffTotals;
+  overwrite: DiffTotals;
+  identical: DiffTotals;
+  delete: DiffTotals;
+}
+
+/** `.romrunner-manifest.json` at a destination root. */
+export interface DeploymentManifest {
+  version: number;
+  deviceProfileId: number;
+  planId: string;
+  deployedAt: string;
+  entries: ManifestEntry[];
+}
+
+export interface ManifestEntry {
+  gameId?: number | null;
+  title?: string | null;
+  kind: DeploymentItemKind;
+  /** Relative to the destination root. */
+  relativePath: string;
+  size: number;
+  hash?: string | null;
+  hashAlgorithm?: HashAlgorithm | null;
+}
+
+export interface HeaderSignature {
+  platformId: string;
+  offset: number;
+  bytesHex: string;
+  searchWindow?: number | null;
+  confidence: Confidence;
+  extensions?: string[] | null;
+}
+
+export interface ExtensionRule {
+  platformId: string;
+  frontendId?: string | null;
+  from: string;
+  to: string;
+}
+
+/** Most specific match (frontend + OS > frontend > OS > neither) wins; none = identity. */
+export interface NameRule {
+  frontendId?: string | null;
+  osId?: string | null;
+  transforms: NameTransform[];
 }
 
+export type NameTransform =
+  | { type: 'strip_region_tags' }
+  | { type: 'lowercase' }
+  | { type: 'replace_illegal_chars'; replacement: string }
+  /** `{n}` = 1-based disc number. */
+  | { type: 'append_disc_token'; format: string };
+
 export interface DeploymentPlan {
+  /** Deterministic: identical config + unchanged contents => identical id. */
   planId: string;
+  configHash: string;
+  contentHash: string;
   deviceProfileId: number;
   destinationPath: string;
   resolvedPaths?: ResolvedDeploymentPaths | null;
   items: DeploymentItem[];
+  /** Excludes skipped items. */
   totalFiles: number;
+  /** Excludes skipped items. */
   totalBytes: number;
+  skippedFiles: number;
+  estimatedSecs: number;
+  /** false = default throughput (no prior deployment to this device). */
+  estimateMeasured: boolean;
   warnings: string[];
 }
 
@@ -377,9 +880,39 @@ export interface ValidationResult {
   valid: boolean;
   errors: string[];
   warnings: string[];
+  writeTest?: WriteTestResult | null;
+  nameIssues: NameIssue[];
 }
 
-export type DeploymentStatus = 'running' | 'paused' | 'cancelled' | 'completed' | 'failed';
+export type NameIssueKind =
+  | 'illegal_chars'
+  | 'trailing_dot_or_space'
+  | 'reserved_name'
+  | 'name_too_long'
+  | 'path_too_long';
+
+export interface NameIssue {
+  destPath: string;
+  gameId?: number | null;
+  kind: NameIssueKind;
+  /** Unique within the plan. */
+  suggested: string;
+}
+
+export type WriteTestStatus =
+  | 'writable'
+  | 'read_only_filesystem'
+  | 'permission_denied'
+  | 'device_not_connected'
+  | 'failed';
+
+export interface WriteTestResult {
+  path: string;
+  status: WriteTestStatus;
+  message?: string | null;
+}
+
+export type DeploymentStatus = 'running' | 'paused' | 'cancelled' | 'completed' | 'failed' | 'interrupted';
 
 export interface DeploymentHandle {
   handleId: string;
@@ -396,6 +929,33 @@ export interface DeploymentRecord {
   totalBytes: number;
   errors: string[];
   warnings: string[];
+  resumable: boolean;
+  rollbackAvailable: boolean;
+  rollbackExpiresAt?: string | null;
+  throughputBps?: number | null;
+  perFileOverheadMs?: number | null;
+}
+
+export type QueueStatus = 'pending' | 'running' | 'completed' | 'failed' | 'cancelled';
+
+export interface QueueEntry {
+  handleId: string;
+  planId: string;
+  deviceProfileId: number;
+  destinationPath: string;
+  status: QueueStatus;
+  /** Among pending entries; null once started. */
+  position?: number | null;
+  enqueuedAt: string;
+  startedAt?: string | null;
+  finishedAt?: string | null;
+}
+
+export interface RollbackResult {
+  handleId: string;
+  removed: number;
+  restored: number;
+  errors: string[];
 }
 
 // ---- BIOS ----
@@ -464,6 +1024,13 @@ export interface BiosVerificationResult {
   matchedBiosId?: string | null;
   matchedBiosName?: string | null;
   matchedPlatform?: string | null;
+  /** `verifyAllBios` only. */
+  relativePath?: string | null;
+  inExpectedSubdirectory?: boolean | null;
+  /** Inside an archive; `path` is then `archive.zip#entry`. */
+  archiveEntry?: string | null;
+  /** `verifyBiosFile` on an archive: per-entry results. */
+  entries?: BiosVerificationResult[] | null;
   notes: string;
 }
 
@@ -475,6 +1042,24 @@ export interface BiosVerificationSummary {
   total: number;
 }
 
+export interface BiosRenameSuggestion {
+  fromPath: string;
+  toPath: string;
+  biosId: string;
+  biosName: string;
+  caseOnly: boolean;
+}
+
+export interface BiosRenameFailure {
+  fromPath: string;
+  error: string;
+}
+
+export interface BiosRenameResult {
+  renamed: BiosRenameSuggestion[];
+  failures: BiosRenameFailure[];
+}
+
 export interface BiosVerificationReport {
   scanDate: string;
   biosDirectory: string;
@@ -489,10 +1074,13 @@ export interface BiosFileRef {
   filename: string;
   region?: Region | null;
   notes?: string | null;
+  /** Emulators that need this file (per-device resolution only). */
+  emulatorIds?: string[] | null;
 }
 
 export interface BiosRequirement {
   platformId: string;
+  emulatorId?: string | null;
   required: BiosFileRef[];
   optional: BiosFileRef[];
 }
@@ -503,6 +1091,9 @@ export interface BiosCompletenessReport {
   missingRequired: BiosFileRef[];
   missingOptional: BiosFileRef[];
   present: BiosFileRef[];
+  satisfiedByHle: BiosFileRef[];
+  /** 0-100; region variants of one BIOS count as a single slot. */
+  completenessPct: number;
   notes: string[];
 }
 
@@ -523,6 +1114,8 @@ export interface GamePerformance {
   notes?: string | null;
   excludeFromAutoLists?: boolean | null;
   source?: string | null;
+  /** Lower-priority sources; single lookups only. */
+  alternatives?: GamePerformance[] | null;
 }
 
 export type CompatStatus = 'perfect' | 'playable' | 'ingame' | 'menu_intro' | 'boots_only' | 'broken' | 'unknown';
@@ -536,6 +1129,22 @@ export interface EmulatorCompat {
   source?: string | null;
   sourceUrl?: string | null;
   lastUpdated?: string | null;
+  /** Lower-priority sources; single lookups only. */
+  alternatives?: EmulatorCompat[] | null;
+}
+
+export interface UserCompatEntries {
+  compat: EmulatorCompat[];
+  performance: GamePerformance[];
+}
+
+/** Rows without a `source` are reported as `pack`. */
+export interface CompatSource {
+  source: string;
+  performanceRows: number;
+  compatRows: number;
+  /** Index in `AppSettings.compatSourcePriority`; null if unlisted. */
+  priority?: number | null;
 }
 
 export interface GameSettings {
@@ -552,6 +1161,46 @@ export interface RefreshResult {
   performanceRows: number;
   compatRows: number;
   settingsRows: number;
+  /** false = rebuilt from the definition pack. */
+  loadedFromDisk: boolean;
+  packVersion?: string | null;
+}
+
+/** Each signal is mapped to 0-1 (missing = 0.5) before weighting. */
+export interface RecommendationWeights {
+  performance: number;
+  compat: number;
+  osDefault: number;
+}
+
+export type RecommendationSource = 'game_override' | 'platform_override' | 'scored';
+
+export interface EmulatorScore {
+  emulatorId: string;
+  score: number;
+  performanceTier?: PerformanceTier | null;
+  compatStatus?: CompatStatus | null;
+}
+
+export interface EmulatorRecommendation {
+  gameId: string;
+  emulatorId: string;
+  source: RecommendationSource;
+  rationale: string;
+  /** Best first. */
+  candidates: EmulatorScore[];
+  weights: RecommendationWeights;
+}
+
+/** Args may contain `{rom_path}`, `{rom_dir}`, `{rom_name}`, `{core_path}`, `{core_name}`. */
+export interface LaunchCommand {
+  gameId: number;
+  deviceProfileId: number;
+  emulatorId: string;
+  coreName?: string | null;
+  executable: string;
+  args: string[];
+  placeholders: Record<string, string>;
 }
 
 // ---- Settings ----
@@ -561,6 +1210,21 @@ export interface ScanSettings {
   trustDetectedLayoutOverExpected: boolean;
   rememberScannedLayouts: boolean;
   rememberUserPathOverrides: boolean;
+  autoTagRules?: AutoTagRule[] | null;
+  scanHistoryRetentionDays?: number | null;
+  excludeGlobs: string[];
+  /** Parallel hashing workers; null = CPU count. */
+  hashConcurrency?: number | null;
+  /** Rescan changed paths while watching. */
+  autoRescanOnChange?: boolean | null;
+  /** null = 2000. */
+  watchDebounceMs?: number | null;
+}
+
+export interface AutoTagRule {
+  flag: string;
+  tag: string;
+  setsIsHack: boolean;
 }
 
 export interface AppSettings {
@@ -568,6 +1232,42 @@ export interface AppSettings {
   defaultBiosDir?: string | null;
   definitionPackPath?: string | null;
   scanSettings: ScanSettings;
+  /** Max concurrent file reads across all scan/verify/hash operations. */
+  ioReadBudget: number;
+  extensionRuleOverrides: ExtensionRule[];
+  regionPriority: Region[];
+  languagePriority: string[];
+  logLevel: LogLevel;
+  /** Conversion scratch dir; null = OS temp. */
+  tempDir?: string | null;
+  /** 0 disables rollback backups. */
+  rollbackWindowHours: number;
+  remoteHosts: RemoteHost[];
+  /** null = search PATH / Android SDK. */
+  adbPath?: string | null;
+  /** `<dir>/<platformId>/<file>`; null = app data `saves`. */
+  localSavesDir?: string | null;
+  /** `<dir>/<platformId>/covers|screenshots/<file>`. */
+  mediaDir?: string | null;
+  /** Default 1; never two entries on one device. */
+  queueConcurrency: number;
+  /** BIOS id -> https URL; wins over the pack's `sourceUrl`. */
+  biosSourceUrls: Record<string, string>;
+  /** Highest priority first; unlisted sources follow by name. `user` is first unless listed. */
+  compatSourcePriority: string[];
+  /** https only; null = official manifest. */
+  packUpdateUrl?: string | null;
+}
+
+/** Secrets live in the OS keychain (`settings.setRemoteSecret`), never here. */
+export type RemoteAuth = { method: 'agent' } | { method: 'key_file'; path: string } | { method: 'password' };
+
+export interface RemoteHost {
+  host: string;
+  port?: number | null;
+  username: string;
+  auth: RemoteAuth;
+  hostKeyFingerprint?: string | null;
 }
 
 export interface SettingsUpdate {
@@ -575,6 +1275,21 @@ export interface SettingsUpdate {
   defaultBiosDir?: string | null;
   definitionPackPath?: string | null;
   scanSettings?: ScanSettings | null;
+  ioReadBudget?: number | null;
+  extensionRuleOverrides?: ExtensionRule[] | null;
+  regionPriority?: Region[] | null;
+  languagePriority?: string[] | null;
+  logLevel?: LogLevel | null;
+  tempDir?: string | null;
+  rollbackWindowHours?: number | null;
+  remoteHosts?: RemoteHost[] | null;
+  adbPath?: string | null;
+  localSavesDir?: string | null;
+  mediaDir?: string | null;
+  queueConcurrency?: number | null;
+  biosSourceUrls?: Record<string, string> | null;
+  compatSourcePriority?: string[] | null;
+  packUpdateUrl?: string | null;
 }
 
 export interface PlatformOverride {
@@ -601,6 +1316,105 @@ export interface UserPathOverrideEntry {
   notes?: string | null;
 }
 
+// ---- Logging ----
+
+export type LogLevel = 'trace' | 'debug' | 'info' | 'warn' | 'error';
+
+export interface LogEntry {
+  timestamp: string;
+  level: LogLevel;
+  target: string;
+  message: string;
+  fields?: Record<string, any> | null;
+}
+
+// ---- Database schema ----
+
+export interface PendingMigration {
+  version: number;
+  name: string;
+}
+
+export interface SchemaStatus {
+  currentVersion: number;
+  targetVersion: number;
+  pending: PendingMigration[];
+  lastError?: string | null;
+  lastBackupPath?: string | null;
+}
+
+export interface DatabaseBackup {
+  path: string;
+  bytes: number;
+  schemaVersion: number;
+  createdAt: string;
+}
+
+export interface SettingsPreset {
+  name: string;
+  settings: AppSettings;
+  createdAt: string;
+  updatedAt: string;
+}
+
+export interface SettingsExport {
+  path: string;
+  bundleVersion: number;
+  platformOverrides: number;
+  gameOverrides: number;
+  pathOverrides: number;
+  /** Overrides for games without a stored hash. */
+  skippedGameIds: number[];
+}
+
+export type SettingsSection = 'app_settings' | 'platform_overrides' | 'game_overrides' | 'path_overrides';
+
+export interface SettingsImportIssue {
+  section: SettingsSection;
+  key: string;
+  message: string;
+  applied: boolean;
+}
+
+export interface SettingsImportResult {
+  bundleVersion: number;
+  settings: AppSettings;
+  platformOverrides: number;
+  gameOverrides: number;
+  pathOverrides: number;
+  issues: SettingsImportIssue[];
+}
+
+// ---- Diagnostics ----
+
+export type HealthStatus = 'ok' | 'degraded' | 'failed';
+
+export interface SubsystemHealth {
+  name: string;
+  status: HealthStatus;
+  message?: string | null;
+}
+
+export interface ActiveTask {
+  kind: string;
+  id: string;
+  startedAt: string;
+}
+
+export interface Diagnostics {
+  generatedAt: string;
+  appVersion: string;
+  schemaVersion?: number | null;
+  targetSchemaVersion: number;
+  definitionPack?: DefinitionPackMeta | null;
+  appDataDir: string;
+  appDataFreeBytes?: number | null;
+  archiveFormats: string[];
+  hashAlgorithms: HashAlgorithm[];
+  activeTasks: ActiveTask[];
+  subsystems: SubsystemHealth[];
+}
+
 // ---- Definitions ----
 
 export interface DefinitionPackMeta {
@@ -608,7 +1422,80 @@ export interface DefinitionPackMeta {
   schemaVersion: string;
   releaseDate: string;
   minAppVersion: string;
+  /** Top layer's path; null for the bundled default. */
   loadedFrom?: string | null;
+  /** Pack paths in layer order; empty for the bundled default alone. */
+  layers: string[];
+  collisions: PackCollision[];
+}
+
+export type PackEntityType = 'platform' | 'emulator' | 'chipset' | 'device' | 'operating_system';
+
+export type PackChangeKind = 'added' | 'removed' | 'modified';
+
+/** null = absent on that side. */
+export interface PackFieldChange {
+  /** JSON pointer relative to the entity. */
+  pointer: string;
+  old?: any | null;
+  new?: any | null;
+}
+
+export interface PackEntityChange {
+  entityType: PackEntityType;
+  id: string;
+  name: string;
+  kind: PackChangeKind;
+  /** Only for `modified`. */
+  fields: PackFieldChange[];
+}
+
+export interface PackDiff {
+  oldVersion: string;
+  newVersion: string;
+  oldSchemaVersion: string;
+  newSchemaVersion: string;
+  /** `<entityType>/<field>` present in only one schema version; not compared. */
+  skippedFields: string[];
+  changes: PackEntityChange[];
+}
+
+export interface PackUpdateInfo {
+  channel: string;
+  version: string;
+  schemaVersion: string;
+  releaseDate: string;
+  minAppVersion: string;
+  /** False when this app build is too old; download is refused. */
+  compatible: boolean;
+  url: string;
+  sizeBytes: number;
+  sha256: string;
+  notes?: string | null;
+}
+
+/** A later layer overriding an entity in a way that looks unintended (informational). */
+export interface PackCollision {
+  entityType: string;
+  id: string;
+  sources: string[];
+  reason: string;
+}
+
+export interface PackIssue {
+  file: string;
+  /** JSON pointer within `file`. */
+  pointer: string;
+  message: string;
+}
+
+export interface PackValidationReport {
+  path: string;
+  schemaVersion?: string | null;
+  /** No errors; warnings don't block loading. */
+  valid: boolean;
+  errors: PackIssue[];
+  warnings: PackIssue[];
 }
 
 export interface Platform {
@@ -623,10 +1510,12 @@ export interface Emulator {
   id: string;
   name: string;
   kind?: string | null;
+  coreName?: string | null;
   platforms?: string[] | null;
   website?: string | null;
   repository?: string | null;
   status?: string | null;
+  splitFormats?: SplitFormat[] | null;
 }
 
 export interface Chipset {
@@ -669,6 +1558,41 @@ export interface Frontend {
   metadataFormat?: string | null;
 }
 
+export type DefaultEmulatorSource = 'os_profile' | 'fallback_defaults' | 'first_supporting';
+
+export interface DefaultEmulator {
+  platformId: string;
+  osId: string;
+  emulator: Emulator;
+  source: DefaultEmulatorSource;
+}
+
+/** yes >= 80% of rated games playable+, partial >= 30%, unknown < 5 rated games. */
+export type PlatformVerdict = 'yes' | 'partial' | 'no' | 'unknown';
+
+export interface ChipsetPlatformCell {
+  platformId: string;
+  verdict: PlatformVerdict;
+  gamesRated: number;
+  /** 0-100. */
+  playablePct: number;
+}
+
+export interface ChipsetComparisonRow {
+  chipset: Chipset;
+  /** Mean `playablePct` over known platforms; null without data. */
+  score?: number | null;
+  deviceIds: string[];
+  /** Same order as `ChipsetComparison.platformIds`. */
+  cells: ChipsetPlatformCell[];
+}
+
+export interface ChipsetComparison {
+  platformIds: string[];
+  /** Best score first. */
+  rows: ChipsetComparisonRow[];
+}
+
 // ---- FS ----
 
 export interface FileFilter {
@@ -689,8 +1613,24 @@ export interface DirectoryInfo {
 export interface CopyResult {
   source: string;
   dest: string;
+  /** Logical size. */
   bytes: number;
   overwritten: boolean;
+  sparse: boolean;
+  /** Allocated size; set only when `sparse` is true. */
+  physicalBytes?: number | null;
+  /** False if the overwrite fell back to writing in place. */
+  atomic: boolean;
+  warnings: string[];
+  hash?: string | null;
+  verified?: boolean | null;
+}
+
+export interface CopyOptions {
+  operationId?: string | null;
+  /** Bytes per chunk; default 1 MiB. */
+  bufferSize?: number | null;
+  verifyHash?: HashAlgorithm | null;
 }
 
 export interface MoveResult {
@@ -704,6 +1644,8 @@ export interface ArchiveEntry {
   path: string;
   isDir: boolean;
   size?: number | null;
+  /** CHD tracks only. */
+  trackType?: string | null;
 }
 
 export interface ExtractResult {
@@ -720,34 +1662,109 @@ export const library = {
   scanDirectory: (path: string, recursive: boolean) =>
     invokeCommand<ScannedGame[]>('scan_directory', { path, recursive }),
 
-  rescanLibrary: () => invokeCommand<ScanResult>('rescan_library'),
+  rescanLibrary: (resume = false) => invokeCommand<ScanResult>('rescan_library', { resume }),
+
+  getScanHistory: (limit?: number | null) => invokeCommand<ScanResult[]>('get_scan_history', { limit: limit ?? null }),
+
+  getScanResult: (scanId: string) => invokeCommand<ScanResult | null>('get_scan_result', { scan_id: scanId }),
+
+  getScanCheckpoint: () => invokeCommand<ScanCheckpoint | null>('get_scan_checkpoint'),
+
+  previewScanExclusions: (path: string, globs?: string[] | null) =>
+    invokeCommand<ExcludedPath[]>('preview_scan_exclusions', { path, globs: globs ?? null }),
+
+  cancelScan: () => invokeCommand<CancelOutcome>('cancel_scan'),
+
+  startLibraryWatch: () => invokeCommand<string[]>('start_library_watch'),
 
-  cancelScan: () => invokeCommand<boolean>('cancel_scan'),
+  stopLibraryWatch: () => invokeCommand<boolean>('stop_library_watch'),
 
-  getGames: (filter: GameFilter, pagination: Pagination) =>
-    invokeCommand<PaginatedGames>('get_games', { filter, pagination }),
+  getGames: (filter: GameFilter, pagination: Pagination, includeFacets = false) =>
+    invokeCommand<PaginatedGames>('get_games', { filter, pagination, include_facets: includeFacets }),
 
   getGame: (id: number) => invokeCommand<Game | null>('get_game', { id }),
 
+  recordPlay: (gameId: number) => invokeCommand<Game>('record_play', { game_id: gameId }),
+
   updateGame: (id: number, updates: GameUpdate) => invokeCommand<Game>('update_game', { id, updates }),
 
-  deleteGames: (ids: number[]) => invokeCommand<DeleteResult>('delete_games', { ids }),
+  updateGames: (ids: number[], updates: GameUpdate, allOrNothing = false) =>
+    invokeCommand<GameBatchResult>('update_games', { ids, updates, all_or_nothing: allOrNothing }),
+
+  deleteGames: (ids: number[], allOrNothing = false, deleteFiles = false, soft = false) =>
+    invokeCommand<DeleteResult>('delete_games', {
+      ids,
+      all_or_nothing: allOrNothing,
+      delete_files: deleteFiles,
+      soft,
+    }),
+
+  restoreGames: (ids: number[]) => invokeCommand<number>('restore_games', { ids }),
+
+  purgeTrash: (olderThanDays?: number | null) =>
+    invokeCommand<number>('purge_trash', { older_than_days: olderThanDays ?? null }),
+
+  rebuildSearchIndex: () => invokeCommand<number>('rebuild_search_index'),
+
+  backfillRegions: () => invokeCommand<BackfillResult>('backfill_regions'),
+
+  verifyLibraryIntegrity: (checkSizes = false) =>
+    invokeCommand<IntegrityReport>('verify_library_integrity', { check_sizes: checkSizes }),
+
+  relinkGame: (gameId: number, newPath: string) =>
+    invokeCommand<Game>('relink_game', { game_id: gameId, new_path: newPath }),
+
+  matchAgainstDat: (gameIds: number[], datPath: string) =>
+    invokeCommand<Record<number, DatMatch>>('match_against_dat', { game_ids: gameIds, dat_path: datPath }),
+
+  findDuplicates: (algorithm: HashAlgorithm = 'sha1') =>
+    invokeCommand<DuplicateGroup[]>('find_duplicates', { algorithm }),
+
+  resolveDuplicates: (keep: number, remove: number[], deleteFiles = false) =>
+    invokeCommand<DeleteResult>('resolve_duplicates', { keep, remove, delete_files: deleteFiles }),
+
+  getIssues: (filter: GameFilter) => invokeCommand<LibraryIssuesReport>('get_issues', { filter }),
 
   getCollections: () => invokeCommand<Collection[]>('get_collections'),
 
   createCollection: (name: string, gameIds: number[]) =>
     invokeCommand<Collection>('create_collection', { name, game_ids: gameIds }),
 
+  createSmartCollection: (name: string, filter: GameFilter) =>
+    invokeCommand<Collection>('create_smart_collection', { name, filter }),
+
   updateCollection: (id: number, updates: CollectionUpdate) =>
     invokeCommand<Collection>('update_collection', { id, updates }),
 
   deleteCollection: (id: number) => invokeCommand<boolean>('delete_collection', { id }),
 
-  addGamesToCollection: (collectionId: number, gameIds: number[]) =>
-    invokeCommand<Collection>('add_games_to_collection', { collection_id: collectionId, game_ids: gameIds }),
+  addGamesToCollection: (collectionId: number, gameIds: number[], allOrNothing = false) =>
+    invokeCommand<CollectionBatchResult>('add_games_to_collection', {
+      collection_id: collectionId,
+      game_ids: gameIds,
+      all_or_nothing: allOrNothing,
+    }),
+
+  removeGamesFromCollection: (collectionId: number, gameIds: number[], allOrNothing = false) =>
+    invokeCommand<CollectionBatchResult>('remove_games_from_collection', {
+      collection_id: collectionId,
+      game_ids: gameIds,
+      all_or_nothing: allOrNothing,
+    }),
+
+  reorderCollection: (collectionId: number, orderedGameIds: number[]) =>
+    invokeCommand<Collection>('reorder_collection', {
+      collection_id: collectionId,
+      ordered_game_ids: orderedGameIds,
+    }),
 
-  removeGamesFromCollection: (collectionId: number, gameIds: number[]) =>
-    invokeCommand<Collection>('remove_games_from_collection', { collection_id: collectionId, game_ids: gameIds }),
+  exportCollection: (collectionId: number, format: PlaylistFormat, path: string, deviceProfileId?: number | null) =>
+    invokeCommand<PlaylistExportResult>('export_collection', {
+      collection_id: collectionId,
+      format,
+      path,
+      device_profile_id: deviceProfileId ?? null,
+    }),
 } as const;
 
 // device::*
@@ -758,6 +1775,9 @@ export const device = {
 
   detectOsLayout: (path: string) => invokeCommand<LayoutDetectionResult>('detect_os_layout', { path }),
 
+  detectInstalledEmulators: (path: string, osId: string) =>
+    invokeCommand<InstalledEmulatorsResult>('detect_installed_emulators', { path, os_id: osId }),
+
   resolveDeploymentPaths: (destinationPath: string, osId: string, destinationId?: string | null) =>
     invokeCommand<ResolvedDeploymentPaths>('resolve_deployment_paths', {
       destination_path: destinationPath,
@@ -765,6 +1785,9 @@ export const device = {
       destination_id: destinationId ?? null,
     }),
 
+  readDeploymentManifest: (path: string) =>
+    invokeCommand<DeploymentManifest | null>('read_deployment_manifest', { path }),
+
   getDevices: () => invokeCommand<UserDevice[]>('get_devices'),
 
   addDevice: (newDevice: NewDevice) => invokeCommand<UserDevice>('add_device', { device: newDevice }),
@@ -790,16 +1813,47 @@ export const deploy = {
 
   validateDeploymentPlan: (plan: DeploymentPlan) => invokeCommand<ValidationResult>('validate_deployment_plan', { plan }),
 
+  previewDeployment: (plan: DeploymentPlan, operationId?: string | null) =>
+    invokeCommand<DeploymentDiff>('preview_deployment', { plan, operation_id: operationId ?? null }),
+
+  cancelPreview: (operationId: string) => invokeCommand<CancelOutcome>('cancel_preview', { operation_id: operationId }),
+
   startDeployment: (plan: DeploymentPlan) => invokeCommand<DeploymentHandle>('start_deployment', { plan }),
 
+  enqueueDeployment: (plan: DeploymentPlan) => invokeCommand<DeploymentHandle>('enqueue_deployment', { plan }),
+
+  getQueue: () => invokeCommand<QueueEntry[]>('get_queue'),
+
   pauseDeployment: (handle: DeploymentHandle) => invokeCommand<boolean>('pause_deployment', { handle }),
 
   resumeDeployment: (handle: DeploymentHandle) => invokeCommand<boolean>('resume_deployment', { handle }),
 
-  cancelDeployment: (handle: DeploymentHandle) => invokeCommand<boolean>('cancel_deployment', { handle }),
+  resumeDeploymentById: (handleId: string) =>
+    invokeCommand<DeploymentHandle>('resume_deployment_by_id', { handle_id: handleId }),
+
+  cancelDeployment: (handle: DeploymentHandle) => invokeCommand<CancelOutcome>('cancel_deployment', { handle }),
+
+  pullSaves: (deviceProfileId: number, destinationPath: string, gameIds?: number[] | null) =>
+    invokeCommand<DeploymentHandle>('pull_saves', {
+      device_profile_id: deviceProfileId,
+      destination_path: destinationPath,
+      game_ids: gameIds ?? null,
+    }),
+
+  rollbackDeployment: (handleId: string) =>
+    invokeCommand<RollbackResult>('rollback_deployment', { handle_id: handleId }),
+
+  confirmDeployment: (handleId: string) => invokeCommand<boolean>('confirm_deployment', { handle_id: handleId }),
 
   getDeploymentHistory: (deviceId?: number | null) =>
     invokeCommand<DeploymentRecord[]>('get_deployment_history', { device_id: deviceId ?? null }),
+
+  exportCollectionPlaylist: (collectionId: number, destPath: string, format: PlaylistFormat) =>
+    invokeCommand<PlaylistExportResult>('export_collection_playlist', {
+      collection_id: collectionId,
+      dest_path: destPath,
+      format,
+    }),
 } as const;
 
 // bios::*
@@ -808,15 +1862,35 @@ export const bios = {
 
   verifyBiosFile: (path: string) => invokeCommand<BiosVerificationResult>('verify_bios_file', { path }),
 
-  verifyAllBios: (directory: string) => invokeCommand<BiosVerificationReport>('verify_all_bios', { directory }),
+  verifyAllBios: (directory: string, maxDepth?: number | null) =>
+    invokeCommand<BiosVerificationReport>('verify_all_bios', { directory, max_depth: maxDepth ?? null }),
+
+  suggestBiosRenames: (directory: string) =>
+    invokeCommand<BiosRenameSuggestion[]>('suggest_bios_renames', { directory }),
+
+  applyBiosRenames: (renames: BiosRenameSuggestion[]) =>
+    invokeCommand<BiosRenameResult>('apply_bios_renames', { renames }),
 
   getBiosRequirements: (platformIds: string[]) => invokeCommand<BiosRequirement[]>('get_bios_requirements', { platform_ids: platformIds }),
 
   getBiosRequirementsForDevice: (deviceProfileId: number) =>
     invokeCommand<BiosRequirement[]>('get_bios_requirements_for_device', { device_profile_id: deviceProfileId }),
 
-  checkBiosCompleteness: (directory: string, platformIds: string[]) =>
-    invokeCommand<BiasCompletenessReport>('check_bios_completeness', { directory, platform_ids: platformIds }),
+  downloadMissingBios: (directory: string, biosIds: string[]) =>
+    invokeCommand<DeploymentHandle>('download_missing_bios', { directory, bios_ids: biosIds }),
+
+  checkBiosCompleteness: (
+    directory: string,
+    platformIds: string[],
+    preferredRegion?: Region | null,
+    hleCountsAsPresent = false,
+  ) =>
+    invokeCommand<BiasCompletenessReport>('check_bios_completeness', {
+      directory,
+      platform_ids: platformIds,
+      preferred_region: preferredRegion ?? null,
+      hle_counts_as_present: hleCountsAsPresent,
+    }),
 } as const;
 
 // compat::*
@@ -833,6 +1907,44 @@ export const compat = {
   getPerformanceBatch: (gameIds: string[], deviceId: string) =>
     invokeCommand<Record<string, GamePerformance>>('get_performance_batch', { game_ids: gameIds, device_id: deviceId }),
 
+  getCompatBatch: (gameIds: string[], emulatorId: string) =>
+    invokeCommand<Record<string, EmulatorCompat>>('get_compat_batch', { game_ids: gameIds, emulator_id: emulatorId }),
+
+  getSettingsBatch: (gameIds: string[], emulatorId: string) =>
+    invokeCommand<Record<string, GameSettings>>('get_settings_batch', { game_ids: gameIds, emulator_id: emulatorId }),
+
+  getLaunchCommand: (gameId: number, deviceProfileId: number) =>
+    invokeCommand<LaunchCommand>('get_launch_command', { game_id: gameId, device_profile_id: deviceProfileId }),
+
+  setUserCompat: (entry: EmulatorCompat) => invokeCommand<EmulatorCompat>('set_user_compat', { entry }),
+
+  setUserPerformance: (entry: GamePerformance) => invokeCommand<GamePerformance>('set_user_performance', { entry }),
+
+  getUserCompatEntries: () => invokeCommand<UserCompatEntries>('get_user_compat_entries'),
+
+  deleteUserCompat: (gameId: string, emulatorId: string) =>
+    invokeCommand<boolean>('delete_user_compat', { game_id: gameId, emulator_id: emulatorId }),
+
+  deleteUserPerformance: (gameId: string, chipsetId: string, emulatorId?: string | null) =>
+    invokeCommand<boolean>('delete_user_performance', {
+      game_id: gameId,
+      chipset_id: chipsetId,
+      emulator_id: emulatorId ?? null,
+    }),
+
+  listCompatSources: () => invokeCommand<CompatSource[]>('list_compat_sources'),
+
+  recommendEmulator: (gameId: string, deviceId: string) =>
+    invokeCommand<EmulatorRecommendation | null>('recommend_emulator', { game_id: gameId, device_id: deviceId }),
+
+  autoList: (deviceId: string, minTier: PerformanceTier, platformIds?: string[] | null, includeUnrated = false) =>
+    invokeCommand<number[]>('auto_list', {
+      device_id: deviceId,
+      min_tier: minTier,
+      platform_ids: platformIds ?? null,
+      include_unrated: includeUnrated,
+    }),
+
   refreshCompatibilityCache: () => invokeCommand<RefreshResult>('refresh_compatibility_cache'),
 
   clearCompatibilityCache: () => invokeCommand<boolean>('clear_compatibility_cache'),
@@ -842,7 +1954,34 @@ export const compat = {
 export const settings = {
   getSettings: () => invokeCommand<AppSettings>('get_settings'),
 
-  updateSettings: (updates: SettingsUpdate) => invokeCommand<AppSettings>('update_settings', { updates }),
+  updateSettings: (updates: SettingsUpdate, force = false) =>
+    invokeCommand<AppSettings>('update_settings', { updates, force }),
+
+  validateSettings: (updates: SettingsUpdate) => invokeCommand<ValidationResult>('validate_settings', { updates }),
+
+  getRecentLogs: (lines: number, level?: LogLevel | null) =>
+    invokeCommand<LogEntry[]>('get_recent_logs', { lines, level: level ?? null }),
+
+  getSchemaStatus: () => invokeCommand<SchemaStatus>('get_schema_status'),
+
+  backupDatabase: (path: string) => invokeCommand<DatabaseBackup>('backup_database', { path }),
+
+  savePreset: (name: string) => invokeCommand<SettingsPreset>('save_preset', { name }),
+
+  listPresets: () => invokeCommand<SettingsPreset[]>('list_presets'),
+
+  applyPreset: (name: string) => invokeCommand<AppSettings>('apply_preset', { name }),
+
+  getActivePreset: () => invokeCommand<string | null>('get_active_preset'),
+
+  deletePreset: (name: string) => invokeCommand<boolean>('delete_preset', { name }),
+
+  exportSettings: (path: string) => invokeCommand<SettingsExport>('export_settings', { path }),
+
+  importSettings: (path: string, merge = true) =>
+    invokeCommand<SettingsImportResult>('import_settings', { path, merge }),
+
+  getDiagnostics: () => invokeCommand<Diagnostics>('get_diagnostics'),
 
   getPlatformOverrides: () => invokeCommand<PlatformOverride[]>('get_platform_overrides'),
 
@@ -864,11 +2003,35 @@ export const settings = {
     invokeCommand<UserPathOverrideEntry>('set_user_path_override', { destination_id: destinationId, entry }),
 
   deleteUserPathOverride: (destinationId: string) => invokeCommand<boolean>('delete_user_path_override', { destination_id: destinationId }),
+
+  setRemoteSecret: (host: string, username: string, secret: string) =>
+    invokeCommand<boolean>('set_remote_secret', { host, username, secret }),
+
+  trustHostKey: (host: string, fingerprint: string) => invokeCommand<RemoteHost>('trust_host_key', { host, fingerprint }),
 } as const;
 
 // definitions::*
 export const definitions = {
-  loadDefinitionPack: (path?: string | null) => invokeCommand<DefinitionPackMeta>('load_definition_pack', { path: path ?? null }),
+  loadDefinitionPack: (path?: string | null, force = false) =>
+    invokeCommand<DefinitionPackMeta>('load_definition_pack', { path: path ?? null, force }),
+
+  loadDefinitionPacks: (paths: string[], force = false) =>
+    invokeCommand<DefinitionPackMeta>('load_definition_packs', { paths, force }),
+
+  enablePackWatch: () => invokeCommand<boolean>('enable_pack_watch'),
+
+  disablePackWatch: () => invokeCommand<boolean>('disable_pack_watch'),
+
+  checkForPackUpdate: (channel: string) =>
+    invokeCommand<PackUpdateInfo | null>('check_for_pack_update', { channel }),
+
+  downloadPackUpdate: () => invokeCommand<DefinitionPackMeta>('download_pack_update'),
+
+  diffPacks: (oldPath: string, newPath: string) =>
+    invokeCommand<PackDiff>('diff_packs', { old_path: oldPath, new_path: newPath }),
+
+  validateDefinitionPack: (path: string) =>
+    invokeCommand<PackValidationReport>('validate_definition_pack', { path }),
 
   getPlatforms: () => invokeCommand<Platform[]>('get_platforms'),
 
@@ -882,15 +2045,31 @@ export const definitions = {
 
   getOperatingSystems: () => invokeCommand<OperatingSystem[]>('get_operating_systems'),
 
+  getOsLayout: (osId: string) => invokeCommand<LayoutTemplate | null>('get_os_layout', { os_id: osId }),
+
   getFrontends: () => invokeCommand<Frontend[]>('get_frontends'),
 
   searchPlatforms: (query: string) => invokeCommand<Platform[]>('search_platforms', { query }),
 
   searchEmulators: (query: string) => invokeCommand<Emulator[]>('search_emulators', { query }),
 
+  getDefaultEmulator: (platformId: string, osId: string) =>
+    invokeCommand<DefaultEmulator | null>('get_default_emulator', { platform_id: platformId, os_id: osId }),
+
   getEmulatorsForPlatform: (platformId: string) => invokeCommand<Emulator[]>('get_emulators_for_platform', { platform_id: platformId }),
 
   getChipsets: () => invokeCommand<Chipset[]>('get_chipsets'),
+
+  compareChipsets: (ids: string[]) => invokeCommand<ChipsetComparison>('compare_chipsets', { ids }),
+
+  getExtensionRules: (frontendId?: string | null) =>
+    invokeCommand<ExtensionRule[]>('get_extension_rules', { frontend_id: frontendId ?? null }),
+
+  getNameRules: (frontendId?: string | null, osId?: string | null) =>
+    invokeCommand<NameRule[]>('get_name_rules', { frontend_id: frontendId ?? null, os_id: osId ?? null }),
+
+  getHeaderSignatures: (platformId?: string | null) =>
+    invokeCommand<HeaderSignature[]>('get_header_signatures', { platform_id: platformId ?? null }),
 } as const;
 
 // fs::*
@@ -903,15 +2082,33 @@ export const fs = {
 
   getDirectoryInfo: (path: string) => invokeCommand<DirectoryInfo>('get_directory_info', { path }),
 
-  calculateFileHash: (path: string, algorithm: HashAlgorithm) =>
-    invokeCommand<string>('calculate_file_hash', { path, algorithm }),
+  calculateFileHash: (path: string, algorithm: HashAlgorithm, operationId?: string | null) =>
+    invokeCommand<string>('calculate_file_hash', { path, algorithm, operation_id: operationId ?? null }),
+
+  calculateFileHashes: (path: string, algorithms: HashAlgorithm[], operationId?: string | null) =>
+    invokeCommand<Partial<Record<HashAlgorithm, string>>>('calculate_file_hashes', {
+      path,
+      algorithms,
+      operation_id: operationId ?? null,
+    }),
+
+  cancelHash: (operationId: string) => invokeCommand<CancelOutcome>('cancel_hash', { operation_id: operationId }),
+
+  clearHashCache: () => invokeCommand<number>('clear_hash_cache'),
 
-  copyFile: (source: string, dest: string) => invokeCommand<CopyResult>('copy_file', { source, dest }),
+  copyFile: (source: string, dest: string, options?: CopyOptions | null) =>
+    invokeCommand<CopyResult>('copy_file', { source, dest, options: options ?? null }),
+
+  cancelCopy: (operationId: string) => invokeCommand<CancelOutcome>('cancel_copy', { operation_id: operationId }),
 
   moveFile: (source: string, dest: string) => invokeCommand<MoveResult>('move_file', { source, dest }),
 
   deleteFile: (path: string) => invokeCommand<boolean>('delete_file', { path }),
 
+  revealInFileManager: (path: string) => invokeCommand<boolean>('reveal_in_file_manager', { path }),
+
+  openPath: (path: string) => invokeCommand<boolean>('open_path', { path }),
+
   listArchiveContents: (path: string) => invokeCommand<ArchiveEntry[]>('list_archive_contents', { path }),
 
   extractArchive: (path: string, dest: string) => invokeCommand<ExtractResult>('extract_archive', { path, dest }),